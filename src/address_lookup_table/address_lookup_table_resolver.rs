@@ -0,0 +1,12 @@
+use solana_sdk::pubkey::Pubkey;
+
+/// Source of address lookup table contents for v0 transactions whose loaded addresses are not
+/// available from status meta (pre-execution feeds, raw wire bytes). Implement this to plug in an
+/// RPC backed or shared cache, or use `InMemoryAddressLookupTableCache`. Resolvers are shared
+/// across threads, e.g. by a `GrpcDriver` running in a spawned task, so they have to be `Send` and
+/// `Sync`.
+pub trait AddressLookupTableResolver: Send + Sync {
+  /// Address stored at `index` in the lookup table at `table_address`. `None` if the table is not
+  /// known or the index is past the end of the table.
+  fn resolve(&self, table_address: &Pubkey, index: u8) -> Option<Pubkey>;
+}
@@ -0,0 +1,96 @@
+use crate::address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+
+/// Address lookup table accounts start with a 56 byte metadata header (type discriminator,
+/// deactivation slot, last extended slot, start index, authority), followed by the addresses
+const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+/// Address lookup table resolver that keeps table contents in memory. Fed with raw lookup table
+/// account data, for example from an account subscription on the address lookup table program.
+#[derive(Default)]
+pub struct InMemoryAddressLookupTableCache {
+  tables: HashMap<Pubkey, Vec<Pubkey>>,
+}
+
+impl InMemoryAddressLookupTableCache {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Insert or replace a table from its raw account data. Returns false without changing the cache
+  /// if the data is too short to be a lookup table account.
+  pub fn insert_account_data(&mut self, table_address: Pubkey, data: &[u8]) -> bool {
+    if data.len() < LOOKUP_TABLE_META_SIZE {
+      return false;
+    }
+    let addresses = data[LOOKUP_TABLE_META_SIZE..]
+      .chunks_exact(32)
+      .map(|bytes| Pubkey::new_from_array(bytes.try_into().unwrap()))
+      .collect();
+    self.tables.insert(table_address, addresses);
+    true
+  }
+
+  /// Insert or replace a table from already decoded addresses.
+  pub fn insert_addresses(&mut self, table_address: Pubkey, addresses: Vec<Pubkey>) {
+    self.tables.insert(table_address, addresses);
+  }
+
+  /// Remove a table, for example after it has been closed.
+  pub fn remove(&mut self, table_address: &Pubkey) {
+    self.tables.remove(table_address);
+  }
+
+  pub fn len(&self) -> usize {
+    self.tables.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.tables.is_empty()
+  }
+}
+
+impl AddressLookupTableResolver for InMemoryAddressLookupTableCache {
+  fn resolve(&self, table_address: &Pubkey, index: u8) -> Option<Pubkey> {
+    self.tables.get(table_address)?.get(index as usize).copied()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn resolves_addresses_after_the_metadata_header() {
+    let table_address = Pubkey::new_unique();
+    let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut data = vec![0u8; LOOKUP_TABLE_META_SIZE];
+    for address in &addresses {
+      data.extend_from_slice(address.as_ref());
+    }
+    let mut cache = InMemoryAddressLookupTableCache::new();
+    assert!(cache.insert_account_data(table_address, &data));
+    assert_eq!(cache.resolve(&table_address, 0), Some(addresses[0]));
+    assert_eq!(cache.resolve(&table_address, 1), Some(addresses[1]));
+    assert_eq!(cache.resolve(&table_address, 2), None);
+  }
+
+  #[test]
+  fn rejects_data_shorter_than_the_header() {
+    let mut cache = InMemoryAddressLookupTableCache::new();
+    assert!(!cache.insert_account_data(Pubkey::new_unique(), &[0u8; 10]));
+    assert!(cache.is_empty());
+  }
+
+  #[test]
+  fn unknown_and_removed_tables_do_not_resolve() {
+    let table_address = Pubkey::new_unique();
+    let mut cache = InMemoryAddressLookupTableCache::new();
+    assert_eq!(cache.resolve(&table_address, 0), None);
+    cache.insert_addresses(table_address, vec![Pubkey::new_unique()]);
+    assert_eq!(cache.len(), 1);
+    cache.remove(&table_address);
+    assert_eq!(cache.resolve(&table_address, 0), None);
+  }
+}
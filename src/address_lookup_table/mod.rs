@@ -0,0 +1,3 @@
+pub mod address_lookup_table_resolver;
+pub mod in_memory_address_lookup_table_cache;
+pub mod resolve_address_table_lookups;
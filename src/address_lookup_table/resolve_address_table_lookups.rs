@@ -0,0 +1,74 @@
+use crate::address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+use solana_sdk::message::v0::MessageAddressTableLookup;
+use solana_sdk::pubkey::Pubkey;
+
+/// Resolve the address table lookups of a v0 message into the loaded account keys. The result is
+/// ordered the same way the runtime appends them to the static account keys: writable addresses
+/// of all lookups first, then readonly addresses of all lookups. Returns `None` if any address
+/// can't be resolved, since a partial key list would shift every index after it.
+pub fn resolve_address_table_lookups(
+  lookups: &[MessageAddressTableLookup],
+  resolver: &dyn AddressLookupTableResolver,
+) -> Option<Vec<Pubkey>> {
+  let num_addresses: usize = lookups
+    .iter()
+    .map(|lookup| lookup.writable_indexes.len() + lookup.readonly_indexes.len())
+    .sum();
+  let mut addresses = Vec::with_capacity(num_addresses);
+  for lookup in lookups {
+    for index in &lookup.writable_indexes {
+      addresses.push(resolver.resolve(&lookup.account_key, *index)?);
+    }
+  }
+  for lookup in lookups {
+    for index in &lookup.readonly_indexes {
+      addresses.push(resolver.resolve(&lookup.account_key, *index)?);
+    }
+  }
+  Some(addresses)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::address_lookup_table::in_memory_address_lookup_table_cache::InMemoryAddressLookupTableCache;
+
+  #[test]
+  fn writable_addresses_of_all_lookups_come_first() {
+    let (table_a, table_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let a: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    let b: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    let mut cache = InMemoryAddressLookupTableCache::new();
+    cache.insert_addresses(table_a, a.clone());
+    cache.insert_addresses(table_b, b.clone());
+    let lookups = [
+      MessageAddressTableLookup {
+        account_key: table_a,
+        writable_indexes: vec![2],
+        readonly_indexes: vec![0],
+      },
+      MessageAddressTableLookup {
+        account_key: table_b,
+        writable_indexes: vec![1],
+        readonly_indexes: vec![2],
+      },
+    ];
+    assert_eq!(
+      resolve_address_table_lookups(&lookups, &cache),
+      Some(vec![a[2], b[1], a[0], b[2]])
+    );
+  }
+
+  #[test]
+  fn any_unresolved_address_is_none() {
+    let table = Pubkey::new_unique();
+    let mut cache = InMemoryAddressLookupTableCache::new();
+    cache.insert_addresses(table, vec![Pubkey::new_unique()]);
+    let lookups = [MessageAddressTableLookup {
+      account_key: table,
+      writable_indexes: vec![0],
+      readonly_indexes: vec![5],
+    }];
+    assert_eq!(resolve_address_table_lookups(&lookups, &cache), None);
+  }
+}
@@ -0,0 +1,318 @@
+use crate::types::sandwich_event::SandwichEvent;
+use crate::types::sandwich_victim::SandwichVictim;
+use crate::utilities::constant_product_amount_out::constant_product_amount_out;
+use solana_central::Pools;
+use solana_central::SwapDirection;
+use solana_central::SwapTx;
+use solana_central::constants::PUMP_CONSTANTS;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+
+/// Find sandwich attacks in the swaps of a block, ordered by index in block and atomic instruction
+/// index. A frontrun and the next swap on the same market in the opposite direction that shares a
+/// signer with it form a sandwich if every swap in between is a victim: a swap in the frontrun
+/// direction from a different transaction with no signer in common with the attacker.
+pub fn detect_sandwiches(swaps: &[SwapTx]) -> Vec<SandwichEvent> {
+  let mut market_swaps: HashMap<Pubkey, Vec<&SwapTx>> = HashMap::new();
+  for swap in swaps {
+    market_swaps
+      .entry(swap.market_address)
+      .or_insert(Vec::new())
+      .push(swap);
+  }
+
+  let mut sandwiches = Vec::new();
+  for (market_address, market_swaps) in market_swaps {
+    let mut i = 0;
+    while i < market_swaps.len() {
+      let Some(j) = find_backrun(&market_swaps, i) else {
+        i += 1;
+        continue;
+      };
+      let frontrun = market_swaps[i];
+      let backrun = market_swaps[j];
+      let victims = (i + 1..j)
+        .map(|k| SandwichVictim {
+          swap: market_swaps[k].clone(),
+          loss: compute_victim_loss(frontrun, market_swaps[k - 1], market_swaps[k]),
+        })
+        .collect();
+      let profit_token = match frontrun.direction {
+        SwapDirection::AToB => frontrun.token_a_address,
+        SwapDirection::BToA => frontrun.token_b_address,
+      };
+      // The backrun can sell more or less than the frontrun bought, only count the sandwiched part
+      let backrun_received = if backrun.swapped_amount_in == 0 {
+        0
+      } else {
+        backrun.swapped_amount_received as u128 * frontrun.swapped_amount_received as u128
+          / backrun.swapped_amount_in as u128
+      };
+      sandwiches.push(SandwichEvent {
+        market_address,
+        frontrun: frontrun.clone(),
+        victims,
+        backrun: backrun.clone(),
+        profit_token,
+        attacker_profit: backrun_received as i128 - frontrun.swapped_amount_in as i128,
+      });
+      i = j + 1;
+    }
+  }
+  sandwiches.sort_by_key(|sandwich| {
+    (
+      sandwich.frontrun.index,
+      sandwich.frontrun.atomic_instruction_index,
+    )
+  });
+  sandwiches
+}
+
+/// Index of the backrun for a frontrun at `frontrun_index` in the swaps of one market, if every
+/// swap between them is a victim and there is at least one
+fn find_backrun(market_swaps: &[&SwapTx], frontrun_index: usize) -> Option<usize> {
+  let frontrun = market_swaps[frontrun_index];
+  let is_attacker = |swap: &SwapTx| !swap.signers.is_disjoint(&frontrun.signers);
+  for (j, swap) in market_swaps.iter().enumerate().skip(frontrun_index + 1) {
+    if swap.signature == frontrun.signature {
+      return None;
+    }
+    if is_attacker(swap) {
+      if swap.direction != frontrun.direction && j > frontrun_index + 1 {
+        return Some(j);
+      }
+      return None;
+    }
+    if swap.direction != frontrun.direction {
+      return None;
+    }
+  }
+  None
+}
+
+/// Victim loss from the constant product counterfactual: the victim swap against the reserves it
+/// actually saw, with the frontrun undone. `previous` is the swap on the market right before the
+/// victim, whose post swap reserves are the reserves the victim saw.
+fn compute_victim_loss(frontrun: &SwapTx, previous: &SwapTx, victim: &SwapTx) -> Option<u64> {
+  let (mut reserve_a, mut reserve_b) = (
+    previous.pool_token_a_vault_amount,
+    previous.pool_token_b_vault_amount,
+  );
+  match frontrun.direction {
+    SwapDirection::AToB => {
+      reserve_a = reserve_a.saturating_sub(frontrun.swapped_amount_in);
+      reserve_b = reserve_b.saturating_add(frontrun.swapped_amount_received);
+    }
+    SwapDirection::BToA => {
+      reserve_b = reserve_b.saturating_sub(frontrun.swapped_amount_in);
+      reserve_a = reserve_a.saturating_add(frontrun.swapped_amount_received);
+    }
+  }
+  match victim.pool {
+    Pools::RaydiumAmmV4 | Pools::RaydiumCpmm | Pools::PumpswapAmm => {}
+    // Bonding curve prices off virtual reserves, vault amounts on the swap are real reserves
+    Pools::PfBondingCurve => {
+      reserve_a += PUMP_CONSTANTS.bc_init_virtual_token_reserve_diff;
+      reserve_b += PUMP_CONSTANTS.bc_init_virtual_sol_reserves;
+    }
+    _ => return None,
+  }
+  let (reserve_in, reserve_out) = match victim.direction {
+    SwapDirection::AToB => (reserve_a, reserve_b),
+    SwapDirection::BToA => (reserve_b, reserve_a),
+  };
+  let counterfactual_received = constant_product_amount_out(
+    victim.swapped_amount_in,
+    reserve_in,
+    reserve_out,
+    victim.fee_fraction_lp,
+  );
+  Some(counterfactual_received.saturating_sub(victim.swapped_amount_received))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utilities::test_swap_tx_builder::TestSwapTxBuilder;
+
+  struct Market {
+    address: Pubkey,
+    token_a: Pubkey,
+    token_b: Pubkey,
+  }
+
+  impl Market {
+    fn new() -> Self {
+      Market {
+        address: Pubkey::new_unique(),
+        token_a: Pubkey::new_unique(),
+        token_b: Pubkey::new_unique(),
+      }
+    }
+
+    /// Builder of a swap on this market
+    fn swap(&self) -> TestSwapTxBuilder {
+      TestSwapTxBuilder::default()
+        .market(self.address)
+        .tokens(self.token_a, self.token_b)
+    }
+  }
+
+  #[test]
+  fn detects_frontrun_victim_backrun() {
+    let market = Market::new();
+    let (attacker, victim) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let swaps = [
+      market
+        .swap()
+        .direction(SwapDirection::BToA)
+        .amounts(100, 1_000)
+        .signer(attacker)
+        .index(0)
+        .build(),
+      market
+        .swap()
+        .direction(SwapDirection::BToA)
+        .amounts(50, 400)
+        .signer(victim)
+        .index(1)
+        .build(),
+      market
+        .swap()
+        .direction(SwapDirection::AToB)
+        .amounts(1_000, 110)
+        .signer(attacker)
+        .index(2)
+        .build(),
+    ];
+    let sandwiches = detect_sandwiches(&swaps);
+    assert_eq!(sandwiches.len(), 1);
+    let sandwich = &sandwiches[0];
+    assert_eq!(sandwich.market_address, market.address);
+    assert_eq!(sandwich.victims.len(), 1);
+    assert_eq!(sandwich.victims[0].swap.index, 1);
+    assert!(sandwich.victims[0].loss.is_some());
+    assert_eq!(sandwich.profit_token, market.token_b);
+    assert_eq!(sandwich.attacker_profit, 10);
+  }
+
+  #[test]
+  fn backrun_selling_half_only_counts_the_sandwiched_part() {
+    let market = Market::new();
+    let (attacker, victim) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let swaps = [
+      market
+        .swap()
+        .direction(SwapDirection::BToA)
+        .amounts(100, 1_000)
+        .signer(attacker)
+        .index(0)
+        .build(),
+      market
+        .swap()
+        .direction(SwapDirection::BToA)
+        .amounts(50, 400)
+        .signer(victim)
+        .index(1)
+        .build(),
+      market
+        .swap()
+        .direction(SwapDirection::AToB)
+        .amounts(2_000, 220)
+        .signer(attacker)
+        .index(2)
+        .build(),
+    ];
+    assert_eq!(detect_sandwiches(&swaps)[0].attacker_profit, 10);
+  }
+
+  #[test]
+  fn opposite_direction_swap_in_between_is_not_a_sandwich() {
+    let market = Market::new();
+    let (attacker, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let swaps = [
+      market
+        .swap()
+        .direction(SwapDirection::BToA)
+        .amounts(100, 1_000)
+        .signer(attacker)
+        .index(0)
+        .build(),
+      market
+        .swap()
+        .direction(SwapDirection::AToB)
+        .amounts(400, 50)
+        .signer(other)
+        .index(1)
+        .build(),
+      market
+        .swap()
+        .direction(SwapDirection::AToB)
+        .amounts(1_000, 110)
+        .signer(attacker)
+        .index(2)
+        .build(),
+    ];
+    assert!(detect_sandwiches(&swaps).is_empty());
+  }
+
+  #[test]
+  fn round_trip_without_victims_is_not_a_sandwich() {
+    let market = Market::new();
+    let attacker = Pubkey::new_unique();
+    let swaps = [
+      market
+        .swap()
+        .direction(SwapDirection::BToA)
+        .amounts(100, 1_000)
+        .signer(attacker)
+        .index(0)
+        .build(),
+      market
+        .swap()
+        .direction(SwapDirection::AToB)
+        .amounts(1_000, 110)
+        .signer(attacker)
+        .index(1)
+        .build(),
+    ];
+    assert!(detect_sandwiches(&swaps).is_empty());
+  }
+
+  #[test]
+  fn swaps_on_other_markets_do_not_break_the_sandwich() {
+    let (market, other_market) = (Market::new(), Market::new());
+    let (attacker, victim) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let swaps = [
+      market
+        .swap()
+        .direction(SwapDirection::BToA)
+        .amounts(100, 1_000)
+        .signer(attacker)
+        .index(0)
+        .build(),
+      other_market
+        .swap()
+        .direction(SwapDirection::AToB)
+        .amounts(10, 10)
+        .signer(victim)
+        .index(1)
+        .build(),
+      market
+        .swap()
+        .direction(SwapDirection::BToA)
+        .amounts(50, 400)
+        .signer(victim)
+        .index(2)
+        .build(),
+      market
+        .swap()
+        .direction(SwapDirection::AToB)
+        .amounts(1_000, 110)
+        .signer(attacker)
+        .index(3)
+        .build(),
+    ];
+    assert_eq!(detect_sandwiches(&swaps).len(), 1);
+  }
+}
@@ -0,0 +1,47 @@
+use crate::types::probable_bundle::ProbableBundle;
+use crate::types::tx_cost::TxCost;
+
+/// Group the `TxCost`s of a block, ordered by index in block, into probable bundles. Consecutive
+/// transactions belong to the same bundle if they share a payer, the tip payer for transactions
+/// that tip and the fee payer otherwise. Only groups that pay at least one tip are returned.
+pub fn group_probable_bundles(tx_costs: &[TxCost]) -> Vec<ProbableBundle> {
+  let mut bundles = Vec::new();
+  let mut current: Option<ProbableBundle> = None;
+  let mut previous: Option<&TxCost> = None;
+  for tx_cost in tx_costs {
+    let payer = match tx_cost.tips.first() {
+      Some(tip) => tip.payer,
+      None => tx_cost.fee_payer,
+    };
+    let tip_lamports: u64 = tx_cost.tips.iter().map(|tip| tip.lamports).sum();
+    let is_consecutive = previous
+      .is_some_and(|previous| previous.slot == tx_cost.slot && previous.index + 1 == tx_cost.index);
+    match current.as_mut() {
+      Some(bundle) if is_consecutive && bundle.tip_payer == payer => {
+        bundle.signatures.push(tx_cost.signature);
+        bundle.tip_lamports += tip_lamports;
+      }
+      _ => {
+        if let Some(bundle) = current.take() {
+          if bundle.tip_lamports > 0 {
+            bundles.push(bundle);
+          }
+        }
+        current = Some(ProbableBundle {
+          slot: tx_cost.slot,
+          start_index: tx_cost.index,
+          signatures: vec![tx_cost.signature],
+          tip_payer: payer,
+          tip_lamports,
+        });
+      }
+    }
+    previous = Some(tx_cost);
+  }
+  if let Some(bundle) = current {
+    if bundle.tip_lamports > 0 {
+      bundles.push(bundle);
+    }
+  }
+  bundles
+}
@@ -0,0 +1,2 @@
+pub mod group_probable_bundles;
+pub mod detect_sandwiches;
@@ -0,0 +1,20 @@
+/*
+Compute budget program and the fee and compute unit defaults the runtime applies to transactions.
+*/
+use solana_sdk::pubkey::Pubkey;
+
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
+  Pubkey::from_str_const("ComputeBudget111111111111111111111111111111");
+
+/// `SetComputeUnitLimit(u32)` instruction tag
+pub const SET_COMPUTE_UNIT_LIMIT_TAG: u8 = 2;
+
+/// `SetComputeUnitPrice(u64)` instruction tag, the price is in micro-lamports per compute unit
+pub const SET_COMPUTE_UNIT_PRICE_TAG: u8 = 3;
+
+/// Compute unit limit per non compute budget instruction when no limit is set
+pub const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
@@ -0,0 +1,54 @@
+/*
+Instruction discriminators not available in `solana_central` constants. Anchor discriminators are
+the first 8 bytes of sha256("global:<instruction name>").
+*/
+
+/// Pumpfun bonding curve `buy_exact_sol_in` instruction
+pub const PF_BUY_EXACT_SOL_IN_DISCRIMINATOR: [u8; 8] = [56, 252, 116, 8, 158, 223, 205, 95];
+
+/// Raydium AMMv4 `swap_base_in` instruction tag. `swap_base_out` is 11
+pub const RAYDIUM_AMMV4_SWAP_BASE_IN_DISCRIMINATOR: u8 = 9;
+
+/// Raydium Cpmm `swap_base_input` instruction. `swap_base_output` is the exact out counterpart
+pub const RAYDIUM_CPMM_SWAP_BASE_INPUT_DISCRIMINATOR: [u8; 8] =
+  [143, 190, 90, 218, 196, 30, 51, 222];
+
+/// Raydium launchpad `buy_exact_in` instruction
+pub const RAYDIUM_LAUNCHPAD_BUY_EXACT_IN_DISCRIMINATOR: [u8; 8] =
+  [250, 234, 13, 123, 213, 156, 19, 236];
+
+/// Raydium launchpad `sell_exact_in` instruction
+pub const RAYDIUM_LAUNCHPAD_SELL_EXACT_IN_DISCRIMINATOR: [u8; 8] =
+  [149, 39, 222, 155, 211, 124, 152, 26];
+
+/// Jupiter v6 `route` instruction
+pub const JUPITER_ROUTE_DISCRIMINATOR: [u8; 8] = [229, 23, 203, 151, 122, 227, 173, 42];
+
+/// Jupiter v6 `shared_accounts_route` instruction, same arguments as `route` after a leading id
+pub const JUPITER_SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR: [u8; 8] =
+  [193, 32, 155, 51, 65, 214, 156, 129];
+
+/// Jupiter v6 `exact_out_route` instruction
+pub const JUPITER_EXACT_OUT_ROUTE_DISCRIMINATOR: [u8; 8] = [208, 51, 239, 151, 123, 43, 237, 92];
+
+/// Jupiter v6 `shared_accounts_exact_out_route` instruction
+pub const JUPITER_SHARED_ACCOUNTS_EXACT_OUT_ROUTE_DISCRIMINATOR: [u8; 8] =
+  [176, 209, 105, 168, 154, 125, 69, 62];
+
+/// Jupiter v6 `route_with_token_ledger` instruction, `route` without the input amount argument
+pub const JUPITER_ROUTE_WITH_TOKEN_LEDGER_DISCRIMINATOR: [u8; 8] =
+  [150, 86, 71, 116, 167, 93, 14, 104];
+
+/// Jupiter v6 `shared_accounts_route_with_token_ledger` instruction
+pub const JUPITER_SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER_DISCRIMINATOR: [u8; 8] =
+  [230, 121, 143, 80, 119, 159, 106, 170];
+
+/// Prefix of Anchor events emitted with self CPI (`emit_cpi!`), sha256("anchor:event") truncated
+/// and little endian. Followed by the 8 byte event discriminator, sha256("event:<event name>")
+pub const ANCHOR_EVENT_CPI_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+/// Jupiter v6 `SwapEvent`, emitted once per hop
+pub const JUPITER_SWAP_EVENT_DISCRIMINATOR: [u8; 8] = [64, 198, 205, 232, 38, 8, 113, 226];
+
+/// Jupiter v6 `FeeEvent`, emitted when a platform fee is charged
+pub const JUPITER_FEE_EVENT_DISCRIMINATOR: [u8; 8] = [73, 79, 78, 127, 184, 213, 13, 220];
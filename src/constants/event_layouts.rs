@@ -0,0 +1,60 @@
+use crate::types::event_layout::EventLayout;
+use crate::types::event_layout_entry::EventLayoutEntry;
+use solana_central::constants::PUMP_CONSTANTS;
+
+/// Pumpfun `TradeEvent` emitted with self CPI, event CPI tag followed by sha256("event:TradeEvent")
+pub const PF_TRADE_EVENT_DISCRIMINATOR: [u8; 16] = [
+  228, 69, 165, 46, 81, 203, 154, 29, 189, 219, 127, 211, 78, 230, 97, 238,
+];
+
+/// Pumpswap `BuyEvent` emitted with self CPI
+pub const PUMPSWAP_BUY_EVENT_DISCRIMINATOR: [u8; 16] = [
+  228, 69, 165, 46, 81, 203, 154, 29, 103, 244, 82, 31, 44, 245, 119, 119,
+];
+
+/// Pumpswap `SellEvent` emitted with self CPI
+pub const PUMPSWAP_SELL_EVENT_DISCRIMINATOR: [u8; 16] = [
+  228, 69, 165, 46, 81, 203, 154, 29, 62, 47, 55, 10, 165, 3, 220, 42,
+];
+
+/*
+Built in event layout registry. Add an entry here when a program upgrade changes an event layout,
+longer events with fields appended to a known layout already decode with the existing entry.
+*/
+pub const EVENT_LAYOUTS: [EventLayoutEntry; 4] = [
+  EventLayoutEntry {
+    program_id: PUMP_CONSTANTS.bonding_curve_program,
+    discriminator: PF_TRADE_EVENT_DISCRIMINATOR,
+    min_len: 137,
+    max_len: Some(266),
+    slots: None,
+    layout: EventLayout::PfTradeEventOld,
+  },
+  // 266 plus ix name strings since the buy_exact_sol_in upgrade (273, 274, 286)
+  EventLayoutEntry {
+    program_id: PUMP_CONSTANTS.bonding_curve_program,
+    discriminator: PF_TRADE_EVENT_DISCRIMINATOR,
+    min_len: 266,
+    max_len: None,
+    slots: None,
+    layout: EventLayout::PfTradeEventCurrent,
+  },
+  // 401 plus ix name strings since the buy_exact_quote_in upgrade (416, 431)
+  EventLayoutEntry {
+    program_id: PUMP_CONSTANTS.pump_swap_program,
+    discriminator: PUMPSWAP_BUY_EVENT_DISCRIMINATOR,
+    min_len: 401,
+    max_len: None,
+    slots: None,
+    layout: EventLayout::PumpswapBuyEvent,
+  },
+  // 14 amounts, 7 accounts and the creator fee fields after the 16 byte prefix
+  EventLayoutEntry {
+    program_id: PUMP_CONSTANTS.pump_swap_program,
+    discriminator: PUMPSWAP_SELL_EVENT_DISCRIMINATOR,
+    min_len: 368,
+    max_len: None,
+    slots: None,
+    layout: EventLayout::PumpswapSellEvent,
+  },
+];
@@ -0,0 +1,23 @@
+use solana_sdk::pubkey::Pubkey;
+
+pub const JUPITER_PERPS_PROGRAM_ID: Pubkey =
+  Pubkey::from_str_const("PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu");
+
+/// Jupiter Perps `IncreasePositionEvent`, emitted when a keeper fulfills an increase request
+pub const JUPITER_PERPS_INCREASE_POSITION_EVENT_DISCRIMINATOR: [u8; 8] =
+  [245, 113, 85, 52, 214, 187, 153, 132];
+
+/// Jupiter Perps `DecreasePositionEvent`, emitted when a keeper fulfills a decrease request
+pub const JUPITER_PERPS_DECREASE_POSITION_EVENT_DISCRIMINATOR: [u8; 8] =
+  [64, 156, 43, 74, 109, 131, 16, 127];
+
+pub const JUPITER_PERPS_LIQUIDATE_FULL_POSITION_EVENT_DISCRIMINATOR: [u8; 8] =
+  [128, 101, 71, 168, 128, 72, 86, 84];
+
+/// Jupiter Perps `InstantIncreasePositionEvent`, position increased without a request
+pub const JUPITER_PERPS_INSTANT_INCREASE_POSITION_EVENT_DISCRIMINATOR: [u8; 8] =
+  [205, 236, 57, 4, 209, 106, 87, 69];
+
+/// Jupiter Perps `InstantDecreasePositionEvent`, position decreased without a request
+pub const JUPITER_PERPS_INSTANT_DECREASE_POSITION_EVENT_DISCRIMINATOR: [u8; 8] =
+  [171, 173, 106, 25, 239, 190, 58, 59];
@@ -0,0 +1,180 @@
+/*
+Instructions of the programs whose swaps and token creations are decoded, used to tell instructions
+that are deliberately not decoded (deposits, withdrawals, pool creation, migrations, fee collection,
+admin) apart from ones added by a protocol upgrade. Anchor discriminators are the first 8 bytes of
+sha256("global:<instruction name>").
+*/
+
+/// Raydium AMMv4 instruction tags, `initialize` (0) through `swap_base_out_v2` (17)
+pub const RAYDIUM_AMMV4_KNOWN_INSTRUCTION_TAGS: &[u8] =
+  &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17];
+
+/// Raydium Cpmm instructions
+pub const RAYDIUM_CPMM_KNOWN_INSTRUCTIONS: &[[u8; 8]] = &[
+  // initialize
+  [175, 175, 109, 31, 13, 152, 155, 237],
+  // initialize_with_permission
+  [63, 55, 254, 65, 49, 178, 89, 121],
+  // deposit
+  [242, 35, 198, 137, 82, 225, 242, 182],
+  // withdraw
+  [183, 18, 70, 156, 148, 109, 161, 34],
+  // swap_base_input
+  [143, 190, 90, 218, 196, 30, 51, 222],
+  // swap_base_output
+  [55, 217, 98, 86, 163, 74, 180, 173],
+  // create_amm_config
+  [137, 52, 237, 212, 215, 117, 108, 104],
+  // update_amm_config
+  [49, 60, 174, 136, 154, 28, 116, 200],
+  // update_pool_status
+  [130, 87, 108, 6, 46, 224, 117, 123],
+  // collect_protocol_fee
+  [136, 136, 252, 221, 194, 66, 126, 89],
+  // collect_fund_fee
+  [167, 138, 78, 149, 223, 194, 6, 126],
+  // collect_creator_fee
+  [20, 22, 86, 123, 198, 28, 219, 132],
+  // create_permission_pda
+  [135, 136, 2, 216, 137, 169, 181, 202],
+  // close_permission_pda
+  [156, 84, 32, 118, 69, 135, 70, 123],
+];
+
+/// Raydium launchpad instructions
+pub const RAYDIUM_LAUNCHPAD_KNOWN_INSTRUCTIONS: &[[u8; 8]] = &[
+  // buy_exact_in
+  [250, 234, 13, 123, 213, 156, 19, 236],
+  // buy_exact_out
+  [24, 211, 116, 40, 105, 3, 153, 56],
+  // sell_exact_in
+  [149, 39, 222, 155, 211, 124, 152, 26],
+  // sell_exact_out
+  [95, 200, 71, 34, 8, 9, 11, 166],
+  // initialize
+  [175, 175, 109, 31, 13, 152, 155, 237],
+  // initialize_v2
+  [67, 153, 175, 39, 218, 16, 38, 32],
+  // initialize_with_token_2022
+  [37, 190, 126, 222, 44, 154, 171, 17],
+  // migrate_to_amm
+  [207, 82, 192, 145, 254, 207, 145, 223],
+  // migrate_to_cpswap
+  [136, 92, 200, 103, 28, 218, 144, 140],
+  // create_config
+  [201, 207, 243, 114, 75, 111, 47, 189],
+  // update_config
+  [29, 158, 252, 191, 10, 83, 219, 99],
+  // create_platform_config
+  [176, 90, 196, 175, 253, 113, 220, 20],
+  // update_platform_config
+  [195, 60, 76, 129, 146, 45, 67, 143],
+  // update_platform_curve_param
+  [138, 144, 138, 250, 220, 128, 4, 57],
+  // remove_platform_curve_param
+  [27, 30, 62, 169, 93, 224, 24, 145],
+  // claim_platform_fee
+  [156, 39, 208, 135, 76, 237, 61, 72],
+  // claim_platform_fee_from_vault
+  [117, 241, 198, 168, 248, 218, 80, 29],
+  // claim_creator_fee
+  [26, 97, 138, 203, 132, 171, 141, 252],
+  // claim_vested_token
+  [49, 33, 104, 30, 189, 157, 79, 35],
+  // create_vesting_account
+  [129, 178, 2, 13, 217, 172, 230, 218],
+  // collect_fee
+  [60, 173, 247, 103, 4, 93, 130, 48],
+  // collect_migrate_fee
+  [255, 186, 150, 223, 235, 118, 201, 186],
+];
+
+/// Pumpswap instructions
+pub const PUMPSWAP_KNOWN_INSTRUCTIONS: &[[u8; 8]] = &[
+  // buy
+  [102, 6, 61, 18, 1, 218, 235, 234],
+  // buy_exact_quote_in
+  [198, 46, 21, 82, 180, 217, 232, 112],
+  // sell
+  [51, 230, 133, 164, 1, 127, 131, 173],
+  // create_pool
+  [233, 146, 209, 142, 207, 104, 64, 188],
+  // deposit
+  [242, 35, 198, 137, 82, 225, 242, 182],
+  // withdraw
+  [183, 18, 70, 156, 148, 109, 161, 34],
+  // create_config
+  [201, 207, 243, 114, 75, 111, 47, 189],
+  // update_fee_config
+  [104, 184, 103, 242, 88, 151, 107, 20],
+  // update_admin
+  [161, 176, 40, 213, 60, 184, 179, 228],
+  // disable
+  [185, 173, 187, 90, 216, 15, 238, 233],
+  // extend_account
+  [234, 102, 194, 203, 150, 72, 62, 229],
+  // collect_coin_creator_fee
+  [160, 57, 89, 42, 181, 139, 43, 66],
+  // set_coin_creator
+  [210, 149, 128, 45, 188, 58, 78, 175],
+  // admin_set_coin_creator
+  [242, 40, 117, 145, 73, 96, 105, 104],
+  // transfer_creator_fees_to_pump
+  [139, 52, 134, 85, 228, 229, 108, 241],
+  // claim_token_incentives
+  [16, 4, 71, 28, 204, 1, 40, 27],
+  // admin_update_token_incentives
+  [209, 11, 115, 87, 213, 23, 124, 204],
+  // init_user_volume_accumulator
+  [94, 6, 202, 115, 255, 96, 232, 183],
+  // sync_user_volume_accumulator
+  [86, 31, 192, 87, 163, 87, 79, 238],
+  // close_user_volume_accumulator
+  [249, 69, 164, 218, 150, 103, 84, 138],
+  // set_reserved_fee_recipients
+  [111, 172, 162, 232, 114, 89, 213, 142],
+];
+
+/// Pumpfun bonding curve instructions
+pub const PF_KNOWN_INSTRUCTIONS: &[[u8; 8]] = &[
+  // initialize
+  [175, 175, 109, 31, 13, 152, 155, 237],
+  // set_params
+  [27, 234, 178, 52, 147, 2, 187, 141],
+  // create
+  [24, 30, 200, 40, 5, 28, 7, 119],
+  // create_v2
+  [214, 144, 76, 236, 95, 139, 49, 180],
+  // buy
+  [102, 6, 61, 18, 1, 218, 235, 234],
+  // buy_exact_sol_in
+  [56, 252, 116, 8, 158, 223, 205, 95],
+  // sell
+  [51, 230, 133, 164, 1, 127, 131, 173],
+  // withdraw
+  [183, 18, 70, 156, 148, 109, 161, 34],
+  // migrate
+  [155, 234, 231, 146, 236, 158, 162, 30],
+  // extend_account
+  [234, 102, 194, 203, 150, 72, 62, 229],
+  // collect_creator_fee
+  [20, 22, 86, 123, 198, 28, 219, 132],
+  // set_creator
+  [254, 148, 255, 112, 207, 142, 170, 165],
+  // admin_set_creator
+  [69, 25, 171, 142, 57, 239, 13, 4],
+  // set_metaplex_creator
+  [138, 96, 174, 217, 48, 85, 197, 246],
+  // update_global_authority
+  [227, 181, 74, 196, 208, 21, 97, 213],
+  // claim_token_incentives
+  [16, 4, 71, 28, 204, 1, 40, 27],
+  // admin_update_token_incentives
+  [209, 11, 115, 87, 213, 23, 124, 204],
+  // init_user_volume_accumulator
+  [94, 6, 202, 115, 255, 96, 232, 183],
+  // sync_user_volume_accumulator
+  [86, 31, 192, 87, 163, 87, 79, 238],
+  // close_user_volume_accumulator
+  [249, 69, 164, 218, 150, 103, 84, 138],
+];
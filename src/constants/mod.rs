@@ -0,0 +1,10 @@
+pub mod compute_budget;
+pub mod discriminators;
+pub mod event_layouts;
+pub mod swap_error_codes;
+pub mod system_program;
+pub mod tip_accounts;
+pub mod token_program;
+pub mod router_programs;
+pub mod jupiter_perps;
+pub mod known_instructions;
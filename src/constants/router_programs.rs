@@ -0,0 +1,13 @@
+use solana_sdk::pubkey::Pubkey;
+
+pub const JUPITER_V6_PROGRAM_ID: Pubkey =
+  Pubkey::from_str_const("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+pub const OKX_ROUTER_PROGRAM_ID: Pubkey =
+  Pubkey::from_str_const("6m2CDdhRgxpH4WjvdzxAYbGxwdGUz5MziiL5jek2kBma");
+
+pub const DFLOW_ROUTER_PROGRAM_ID: Pubkey =
+  Pubkey::from_str_const("DF1ow4tspfHX9JwWJsAb9epbkA8hmpSEAtxXy1V27QBH");
+
+pub const TITAN_ROUTER_PROGRAM_ID: Pubkey =
+  Pubkey::from_str_const("T1TANpTeScyeqVzzgNViGDNrkQ6qHz9KrSBS4aNXvGT");
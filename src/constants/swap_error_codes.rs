@@ -0,0 +1,20 @@
+/*
+Custom program error codes returned by swap programs, used to tell why a swap attempt failed.
+Anchor programs number their errors from 6000.
+*/
+
+/// SPL token `InsufficientFunds`, propagated unchanged through the swap program's CPI. Other
+/// programs use code 1 for their own errors, so it only means this if a token program returned it
+pub const TOKEN_INSUFFICIENT_FUNDS_ERROR_CODE: u32 = 1;
+
+/// Raydium AMMv4 `ExceededSlippage`
+pub const RAYDIUM_AMMV4_EXCEEDED_SLIPPAGE_ERROR_CODE: u32 = 30;
+
+/// Raydium Cpmm `ExceededSlippage`
+pub const RAYDIUM_CPMM_EXCEEDED_SLIPPAGE_ERROR_CODE: u32 = 6005;
+
+/// Pumpswap `ExceededSlippage`
+pub const PUMPSWAP_EXCEEDED_SLIPPAGE_ERROR_CODE: u32 = 6004;
+
+/// Pumpfun `TooMuchSolRequired` (buy) and `TooLittleSolReceived` (sell)
+pub const PF_SLIPPAGE_ERROR_CODES: [u32; 2] = [6002, 6003];
@@ -0,0 +1,6 @@
+use solana_sdk::pubkey::Pubkey;
+
+pub const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::from_str_const("11111111111111111111111111111111");
+
+/// System program `Transfer { lamports: u64 }` instruction tag, encoded as a little endian u32
+pub const SYSTEM_TRANSFER_TAG: u32 = 2;
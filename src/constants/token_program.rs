@@ -0,0 +1,16 @@
+use solana_sdk::pubkey::Pubkey;
+
+pub const TOKEN_PROGRAM_ID: Pubkey =
+  Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const TOKEN_2022_PROGRAM_ID: Pubkey =
+  Pubkey::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC8PCnkrB2j6TnKgX");
+
+/// `Transfer { amount: u64 }` instruction tag, shared by SPL Token and Token-2022
+pub const TOKEN_TRANSFER_TAG: u8 = 3;
+/// `TransferChecked { amount: u64, decimals: u8 }` instruction tag, shared by SPL Token and
+/// Token-2022
+pub const TOKEN_TRANSFER_CHECKED_TAG: u8 = 12;
+/// Token-2022 transfer fee extension instruction tag, followed by the extension instruction tag
+pub const TOKEN_2022_TRANSFER_FEE_EXTENSION_TAG: u8 = 26;
+/// Transfer fee extension `TransferCheckedWithFee { amount: u64, decimals: u8, fee: u64 }` tag
+pub const TOKEN_2022_TRANSFER_CHECKED_WITH_FEE_TAG: u8 = 1;
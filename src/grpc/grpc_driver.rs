@@ -0,0 +1,378 @@
+use crate::grpc::grpc_driver_options::GrpcDriverOptions;
+use crate::grpc::slot_buffer::SlotBuffer;
+use crate::tx::decode_tx_source::decode_tx_source;
+use crate::tx::send_decoded_tx::send_decoded_tx;
+use crate::types::decode_senders::DecodeSenders;
+use crate::types::grpc_tx::GrpcTx;
+use crate::types::slot_rollback::SlotRollback;
+use crate::types::slot_rollback_reason::SlotRollbackReason;
+use futures_util::Stream;
+use futures_util::StreamExt;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+use tonic::Status;
+use yellowstone_grpc_proto::geyser::CommitmentLevel;
+use yellowstone_grpc_proto::geyser::SlotStatus;
+use yellowstone_grpc_proto::geyser::SubscribeUpdate;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateSlot;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransaction;
+use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
+
+/// Drives decoding from a Yellowstone gRPC `SubscribeUpdate` stream subscribed to transactions and
+/// slot updates. Transactions are decoded as they arrive and their output is buffered per slot
+/// until the slot reaches `commitment`, then written to the channels in `senders` in block order.
+/// Dead slots, and slots below a finalized slot that were never finalized themselves, are dropped
+/// with a `SlotRollback` on `slot_rollback_sender`. This relies on finalized statuses arriving in
+/// slot order, the way Yellowstone sends them for rooted slots. Block time is not part of
+/// transaction updates, the `created_at` time of the update is used instead. Transactions that
+/// arrive late for a rolled back slot are dropped.
+pub struct GrpcDriver {
+  senders: DecodeSenders,
+  options: GrpcDriverOptions,
+  /// Commitment at which buffered output of a slot is released
+  commitment: CommitmentLevel,
+  /// Slots above the last finalized slot
+  slots: BTreeMap<u64, SlotBuffer>,
+  last_finalized_slot: Option<u64>,
+  /// The most recent rolled back slots, at most `MAX_ROLLED_BACK_SLOTS`
+  rolled_back_slots: BTreeSet<u64>,
+}
+
+/// Number of rolled back slots remembered to drop late transactions for, about 7 minutes of slots
+const MAX_ROLLED_BACK_SLOTS: usize = 1024;
+
+impl GrpcDriver {
+  pub fn new(
+    senders: DecodeSenders,
+    options: GrpcDriverOptions,
+    commitment: CommitmentLevel,
+  ) -> Self {
+    GrpcDriver {
+      senders,
+      options,
+      commitment,
+      slots: BTreeMap::new(),
+      last_finalized_slot: None,
+      rolled_back_slots: BTreeSet::new(),
+    }
+  }
+
+  /// Consume `stream` until it ends or errors, e.g. the `Streaming` returned by a Yellowstone
+  /// client `subscribe` call. Pings are not answered, that is up to the owner of the request sink.
+  pub async fn run<S>(&mut self, mut stream: S) -> Result<(), Status>
+  where
+    S: Stream<Item = Result<SubscribeUpdate, Status>> + Unpin,
+  {
+    while let Some(update) = stream.next().await {
+      self.handle_update(update?);
+    }
+    Ok(())
+  }
+
+  pub fn handle_update(&mut self, update: SubscribeUpdate) {
+    match update.update_oneof {
+      Some(UpdateOneof::Transaction(transaction)) => {
+        let received_time = match update.created_at {
+          Some(created_at) => created_at.seconds as u64,
+          None => SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("handle_update: System time before unix epoch")
+            .as_secs(),
+        };
+        self.handle_transaction(transaction, received_time);
+      }
+      Some(UpdateOneof::Slot(slot_update)) => self.handle_slot(slot_update),
+      _ => {}
+    }
+  }
+
+  fn handle_transaction(&mut self, transaction: SubscribeUpdateTransaction, received_time: u64) {
+    let Some(info) = transaction.transaction else {
+      return;
+    };
+    let (Some(tx), Some(meta)) = (&info.transaction, &info.meta) else {
+      return;
+    };
+    let slot = transaction.slot;
+    // Output of an abandoned fork, its rollback notice was already sent
+    if self.rolled_back_slots.contains(&slot) {
+      return;
+    }
+    let grpc_tx = GrpcTx { tx, meta };
+    let options = self.options.decode_options();
+    let Some(decoded_tx) = decode_tx_source(&grpc_tx, &options, received_time, slot, info.index)
+    else {
+      return;
+    };
+    // Slots at or below the last finalized slot are no longer tracked, late transactions for them
+    // are sent right away
+    if self
+      .last_finalized_slot
+      .is_some_and(|finalized_slot| slot <= finalized_slot)
+    {
+      send_decoded_tx(decoded_tx, &self.senders);
+      return;
+    }
+    let slot_buffer = self.slots.entry(slot).or_default();
+    if slot_buffer.released {
+      slot_buffer.released_txs += 1;
+      send_decoded_tx(decoded_tx, &self.senders);
+    } else {
+      slot_buffer.decoded_txs.push((info.index, decoded_tx));
+    }
+  }
+
+  fn handle_slot(&mut self, slot_update: SubscribeUpdateSlot) {
+    let slot = slot_update.slot;
+    if self.rolled_back_slots.contains(&slot)
+      || self
+        .last_finalized_slot
+        .is_some_and(|finalized_slot| slot <= finalized_slot)
+    {
+      return;
+    }
+    let commitment = match SlotStatus::try_from(slot_update.status) {
+      Ok(SlotStatus::SlotProcessed) => CommitmentLevel::Processed,
+      Ok(SlotStatus::SlotConfirmed) => CommitmentLevel::Confirmed,
+      Ok(SlotStatus::SlotFinalized) => CommitmentLevel::Finalized,
+      Ok(SlotStatus::SlotDead) => {
+        self.roll_back(slot, SlotRollbackReason::Dead(slot_update.dead_error));
+        return;
+      }
+      _ => return,
+    };
+    if commitment >= self.commitment {
+      self.release(slot);
+    }
+    if commitment == CommitmentLevel::Finalized {
+      // Every tracked slot below a finalized slot would have been finalized first if it was rooted
+      let skipped_slots: Vec<u64> = self.slots.range(..slot).map(|(slot, _)| *slot).collect();
+      for skipped_slot in skipped_slots {
+        self.roll_back(skipped_slot, SlotRollbackReason::Skipped);
+      }
+      self.slots.remove(&slot);
+      self.last_finalized_slot = Some(slot);
+    }
+  }
+
+  fn release(&mut self, slot: u64) {
+    let slot_buffer = self.slots.entry(slot).or_default();
+    if slot_buffer.released {
+      return;
+    }
+    let mut decoded_txs = std::mem::take(&mut slot_buffer.decoded_txs);
+    slot_buffer.released = true;
+    slot_buffer.released_txs += decoded_txs.len();
+    decoded_txs.sort_by_key(|(index, _)| *index);
+    for (_, decoded_tx) in decoded_txs {
+      send_decoded_tx(decoded_tx, &self.senders);
+    }
+  }
+
+  fn roll_back(&mut self, slot: u64, reason: SlotRollbackReason) {
+    let slot_buffer = self.slots.remove(&slot).unwrap_or_default();
+    self.rolled_back_slots.insert(slot);
+    if self.rolled_back_slots.len() > MAX_ROLLED_BACK_SLOTS {
+      self.rolled_back_slots.pop_first();
+    }
+    let _ = self.senders.slot_rollback_sender.send(SlotRollback {
+      slot,
+      reason,
+      txs: slot_buffer.released_txs + slot_buffer.decoded_txs.len(),
+      released: slot_buffer.released,
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::types::tx_cost::TxCost;
+  use futures_util::FutureExt;
+  use futures_util::stream;
+  use solana_sdk::pubkey::Pubkey;
+  use tokio::sync::broadcast::Receiver;
+  use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+  use yellowstone_grpc_proto::solana::storage::confirmed_block::Message;
+  use yellowstone_grpc_proto::solana::storage::confirmed_block::MessageHeader;
+  use yellowstone_grpc_proto::solana::storage::confirmed_block::Transaction;
+  use yellowstone_grpc_proto::solana::storage::confirmed_block::TransactionStatusMeta;
+
+  /// Transfer-less transaction at `index` of `slot`, decoded into just its `TxCost`
+  fn transaction_update(slot: u64, index: u64) -> SubscribeUpdate {
+    let message = Message {
+      header: Some(MessageHeader {
+        num_required_signatures: 1,
+        ..Default::default()
+      }),
+      account_keys: vec![Pubkey::new_unique().to_bytes().to_vec()],
+      ..Default::default()
+    };
+    let info = SubscribeUpdateTransactionInfo {
+      transaction: Some(Transaction {
+        signatures: vec![vec![1; 64]],
+        message: Some(message),
+      }),
+      meta: Some(TransactionStatusMeta {
+        fee: 5000,
+        pre_balances: vec![1_000_000],
+        post_balances: vec![995_000],
+        ..Default::default()
+      }),
+      index,
+      ..Default::default()
+    };
+    SubscribeUpdate {
+      update_oneof: Some(UpdateOneof::Transaction(SubscribeUpdateTransaction {
+        transaction: Some(info),
+        slot,
+      })),
+      ..Default::default()
+    }
+  }
+
+  fn slot_update(slot: u64, status: SlotStatus, dead_error: Option<&str>) -> SubscribeUpdate {
+    SubscribeUpdate {
+      update_oneof: Some(UpdateOneof::Slot(SubscribeUpdateSlot {
+        slot,
+        status: status as i32,
+        dead_error: dead_error.map(str::to_string),
+        ..Default::default()
+      })),
+      ..Default::default()
+    }
+  }
+
+  /// (slot, index) of every transaction released so far
+  fn released(tx_costs: &mut Receiver<TxCost>) -> Vec<(u64, u64)> {
+    let mut released = Vec::new();
+    while let Ok(tx_cost) = tx_costs.try_recv() {
+      released.push((tx_cost.slot, tx_cost.index));
+    }
+    released
+  }
+
+  fn rollbacks(slot_rollbacks: &mut Receiver<SlotRollback>) -> Vec<SlotRollback> {
+    let mut rollbacks = Vec::new();
+    while let Ok(slot_rollback) = slot_rollbacks.try_recv() {
+      rollbacks.push(slot_rollback);
+    }
+    rollbacks
+  }
+
+  #[test]
+  fn releases_in_block_order_at_the_commitment() {
+    let senders = DecodeSenders::new(64);
+    let mut tx_costs = senders.tx_cost_sender.subscribe();
+    let mut driver = GrpcDriver::new(
+      senders,
+      GrpcDriverOptions::default(),
+      CommitmentLevel::Confirmed,
+    );
+
+    driver.handle_update(transaction_update(10, 2));
+    driver.handle_update(transaction_update(10, 0));
+    driver.handle_update(slot_update(10, SlotStatus::SlotProcessed, None));
+    assert!(released(&mut tx_costs).is_empty());
+
+    driver.handle_update(slot_update(10, SlotStatus::SlotConfirmed, None));
+    assert_eq!(released(&mut tx_costs), vec![(10, 0), (10, 2)]);
+
+    // Transactions of a released slot are sent right away
+    driver.handle_update(transaction_update(10, 1));
+    assert_eq!(released(&mut tx_costs), vec![(10, 1)]);
+  }
+
+  #[test]
+  fn rolls_back_dead_slots_once() {
+    let senders = DecodeSenders::new(64);
+    let mut tx_costs = senders.tx_cost_sender.subscribe();
+    let mut slot_rollbacks = senders.slot_rollback_sender.subscribe();
+    let mut driver = GrpcDriver::new(
+      senders,
+      GrpcDriverOptions::default(),
+      CommitmentLevel::Confirmed,
+    );
+
+    driver.handle_update(transaction_update(11, 0));
+    driver.handle_update(slot_update(11, SlotStatus::SlotDead, Some("dead")));
+    let rollbacks = rollbacks(&mut slot_rollbacks);
+    assert_eq!(rollbacks.len(), 1);
+    assert_eq!(rollbacks[0].slot, 11);
+    assert_eq!(
+      rollbacks[0].reason,
+      SlotRollbackReason::Dead(Some("dead".to_string()))
+    );
+    assert_eq!(rollbacks[0].txs, 1);
+    assert!(!rollbacks[0].released);
+
+    // A late transaction doesn't bring the slot back
+    driver.handle_update(transaction_update(11, 1));
+    driver.handle_update(slot_update(11, SlotStatus::SlotConfirmed, None));
+    driver.handle_update(slot_update(12, SlotStatus::SlotFinalized, None));
+    assert!(released(&mut tx_costs).is_empty());
+    assert!(slot_rollbacks.try_recv().is_err());
+  }
+
+  #[test]
+  fn rolls_back_slots_skipped_by_a_finalized_slot() {
+    let senders = DecodeSenders::new(64);
+    let mut tx_costs = senders.tx_cost_sender.subscribe();
+    let mut slot_rollbacks = senders.slot_rollback_sender.subscribe();
+    let mut driver = GrpcDriver::new(
+      senders,
+      GrpcDriverOptions::default(),
+      CommitmentLevel::Processed,
+    );
+
+    driver.handle_update(transaction_update(12, 0));
+    driver.handle_update(slot_update(12, SlotStatus::SlotProcessed, None));
+    driver.handle_update(transaction_update(13, 0));
+    driver.handle_update(transaction_update(14, 0));
+    driver.handle_update(slot_update(14, SlotStatus::SlotFinalized, None));
+    assert_eq!(released(&mut tx_costs), vec![(12, 0), (14, 0)]);
+
+    let rollbacks = rollbacks(&mut slot_rollbacks);
+    assert_eq!(rollbacks.len(), 2);
+    assert_eq!(rollbacks[0].slot, 12);
+    assert_eq!(rollbacks[0].reason, SlotRollbackReason::Skipped);
+    assert!(rollbacks[0].released);
+    assert_eq!(rollbacks[1].slot, 13);
+    assert_eq!(rollbacks[1].txs, 1);
+    assert!(!rollbacks[1].released);
+
+    // Late transactions of skipped slots are dropped, other slots below the finalized slot are sent
+    driver.handle_update(transaction_update(13, 1));
+    driver.handle_update(transaction_update(9, 0));
+    assert_eq!(released(&mut tx_costs), vec![(9, 0)]);
+  }
+
+  #[test]
+  fn runs_a_stream_and_can_be_spawned() {
+    fn assert_send<T: Send + 'static>(_: &T) {}
+
+    let senders = DecodeSenders::new(64);
+    let mut tx_costs = senders.tx_cost_sender.subscribe();
+    let driver = GrpcDriver::new(
+      senders,
+      GrpcDriverOptions::default(),
+      CommitmentLevel::Finalized,
+    );
+    let updates: Vec<Result<SubscribeUpdate, Status>> = vec![
+      Ok(transaction_update(20, 1)),
+      Ok(transaction_update(20, 0)),
+      Ok(slot_update(20, SlotStatus::SlotConfirmed, None)),
+      Ok(slot_update(20, SlotStatus::SlotFinalized, None)),
+    ];
+    let run = async move {
+      let mut driver = driver;
+      driver.run(stream::iter(updates)).await
+    };
+    assert_send(&run);
+    // The stream never waits, so the driver runs to completion on the first poll
+    assert!(run.now_or_never().unwrap().is_ok());
+    assert_eq!(released(&mut tx_costs), vec![(20, 0), (20, 1)]);
+  }
+}
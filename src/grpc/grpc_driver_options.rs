@@ -0,0 +1,32 @@
+use crate::address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+use crate::types::decode_filter::DecodeFilter;
+use crate::types::decode_options::DecodeOptions;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// `DecodeOptions` owned by a `GrpcDriver`, so the driver and its `run` future don't borrow
+/// anything and can be moved into a spawned task. See `DecodeOptions` for the fields.
+#[derive(Default, Clone)]
+pub struct GrpcDriverOptions {
+  pub address_lookup_table_resolver: Option<Arc<dyn AddressLookupTableResolver>>,
+  pub decode_failed_txs: bool,
+  pub tip_accounts: Option<Arc<HashSet<Pubkey>>>,
+  pub reconcile_token_balances: bool,
+  pub decode_log_events: bool,
+  pub filter: Option<Arc<DecodeFilter>>,
+}
+
+impl GrpcDriverOptions {
+  /// Borrow these options as the `DecodeOptions` transactions are decoded with
+  pub fn decode_options(&self) -> DecodeOptions<'_> {
+    DecodeOptions {
+      address_lookup_table_resolver: self.address_lookup_table_resolver.as_deref(),
+      decode_failed_txs: self.decode_failed_txs,
+      tip_accounts: self.tip_accounts.as_deref(),
+      reconcile_token_balances: self.reconcile_token_balances,
+      decode_log_events: self.decode_log_events,
+      filter: self.filter.as_deref(),
+    }
+  }
+}
@@ -0,0 +1,3 @@
+pub mod grpc_driver;
+pub mod grpc_driver_options;
+pub mod slot_buffer;
@@ -0,0 +1,12 @@
+use crate::types::decoded_tx::DecodedTx;
+
+/// Output of a slot that `GrpcDriver` holds until the slot reaches the release commitment.
+#[derive(Default)]
+pub struct SlotBuffer {
+  /// Decoded transactions with their index in the block, not released yet
+  pub decoded_txs: Vec<(u64, DecodedTx)>,
+  /// Whether the slot reached the release commitment, later transactions are sent right away
+  pub released: bool,
+  /// Number of decoded transactions already written to the channels
+  pub released_txs: usize,
+}
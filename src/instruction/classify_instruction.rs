@@ -1,3 +1,4 @@
+use crate::instruction::jupiter_perps::is_jupiter_perps_event_instruction::is_jupiter_perps_event_instruction;
 use crate::instruction::pumpfun::is_pf_bonding_curve_create_instruction::is_pf_bonding_curve_create_instruction;
 use crate::instruction::pumpfun::is_pumpfun_event_instruction::is_pumpfun_event_instruction;
 use crate::instruction::pumpswap::is_pumpswap_swap_instruction::is_pumpswap_swap_instruction;
@@ -33,6 +34,8 @@ pub fn classify_instruction(instruction: &Instruction) -> (InstructionType, Swap
     return (InstructionType::PfBondingCurveSwap, SwapDirection::AToB);
   } else if is_pf_bonding_curve_create_instruction(instruction) {
     return (InstructionType::PfBondingCurveCreate, SwapDirection::AToB);
+  } else if is_jupiter_perps_event_instruction(instruction) {
+    return (InstructionType::JupiterPerpsEvent, SwapDirection::AToB);
   } else {
     return (InstructionType::None, SwapDirection::AToB);
   }
@@ -0,0 +1,22 @@
+use crate::instruction::classify_instruction::classify_instruction;
+use crate::instruction::pumpfun::is_pf_bonding_curve_swap_instruction::is_pf_bonding_curve_swap_instruction;
+use crate::types::instruction_type::InstructionType;
+use solana_central::Instruction;
+use solana_central::SwapDirection;
+
+/// Classify an instruction of a transaction without usable outcome data (no meta, or failed). Same
+/// as `classify_instruction`, except Pumpfun `buy`/`sell` instructions are classified as
+/// `PfBondingCurveSwap` since there is no event to decode the swap from. Pumpfun self CPI event
+/// instructions are classified as `None` so swaps that executed inside a failed transaction are not
+/// counted twice.
+pub fn classify_pending_instruction(instruction: &Instruction) -> (InstructionType, SwapDirection) {
+  let (is_pf_swap, pf_swap_direction) = is_pf_bonding_curve_swap_instruction(instruction);
+  if is_pf_swap {
+    return (InstructionType::PfBondingCurveSwap, pf_swap_direction);
+  }
+  let (instruction_type, swap_direction) = classify_instruction(instruction);
+  if instruction_type == InstructionType::PfBondingCurveSwap {
+    return (InstructionType::None, swap_direction);
+  }
+  (instruction_type, swap_direction)
+}
@@ -0,0 +1,105 @@
+use crate::constants::swap_error_codes::PF_SLIPPAGE_ERROR_CODES;
+use crate::constants::swap_error_codes::PUMPSWAP_EXCEEDED_SLIPPAGE_ERROR_CODE;
+use crate::constants::swap_error_codes::RAYDIUM_AMMV4_EXCEEDED_SLIPPAGE_ERROR_CODE;
+use crate::constants::swap_error_codes::RAYDIUM_CPMM_EXCEEDED_SLIPPAGE_ERROR_CODE;
+use crate::constants::swap_error_codes::TOKEN_INSUFFICIENT_FUNDS_ERROR_CODE;
+use crate::constants::token_program::TOKEN_2022_PROGRAM_ID;
+use crate::constants::token_program::TOKEN_PROGRAM_ID;
+use crate::types::swap_failure_kind::SwapFailureKind;
+use solana_central::Pools;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+
+/// Classify why a swap on `pool` failed given the transaction error it failed with. Assumes the
+/// error belongs to the instruction the swap is in. `failed_program` is the program that returned
+/// the error, custom error codes are only read as token program errors if it is a token program.
+pub fn classify_swap_failure(
+  pool: &Pools,
+  error: &TransactionError,
+  failed_program: Option<&Pubkey>,
+) -> SwapFailureKind {
+  match error {
+    TransactionError::InsufficientFundsForFee
+    | TransactionError::InsufficientFundsForRent { .. } => SwapFailureKind::InsufficientFunds,
+    TransactionError::InstructionError(_, InstructionError::InsufficientFunds) => {
+      SwapFailureKind::InsufficientFunds
+    }
+    TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+      let code = *code;
+      let is_slippage = match pool {
+        Pools::RaydiumAmmV4 => code == RAYDIUM_AMMV4_EXCEEDED_SLIPPAGE_ERROR_CODE,
+        Pools::RaydiumCpmm => code == RAYDIUM_CPMM_EXCEEDED_SLIPPAGE_ERROR_CODE,
+        Pools::PumpswapAmm => code == PUMPSWAP_EXCEEDED_SLIPPAGE_ERROR_CODE,
+        Pools::PfBondingCurve => PF_SLIPPAGE_ERROR_CODES.contains(&code),
+        _ => false,
+      };
+      if is_slippage {
+        SwapFailureKind::SlippageExceeded
+      } else if code == TOKEN_INSUFFICIENT_FUNDS_ERROR_CODE
+        && failed_program.is_some_and(|program_id| {
+          *program_id == TOKEN_PROGRAM_ID || *program_id == TOKEN_2022_PROGRAM_ID
+        })
+      {
+        SwapFailureKind::InsufficientFunds
+      } else {
+        SwapFailureKind::CustomProgramError
+      }
+    }
+    _ => SwapFailureKind::Other,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use solana_central::constants::RAYDIUM_CONSTANTS;
+
+  fn custom_error(code: u32) -> TransactionError {
+    TransactionError::InstructionError(1, InstructionError::Custom(code))
+  }
+
+  #[test]
+  fn custom_code_1_is_insufficient_funds_only_from_token_programs() {
+    let error = custom_error(TOKEN_INSUFFICIENT_FUNDS_ERROR_CODE);
+    for program_id in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+      assert_eq!(
+        classify_swap_failure(&Pools::RaydiumCpmm, &error, Some(&program_id)),
+        SwapFailureKind::InsufficientFunds
+      );
+    }
+    assert_eq!(
+      classify_swap_failure(
+        &Pools::RaydiumAmmV4,
+        &error,
+        Some(&RAYDIUM_CONSTANTS.amm_program)
+      ),
+      SwapFailureKind::CustomProgramError
+    );
+    assert_eq!(
+      classify_swap_failure(&Pools::RaydiumAmmV4, &error, None),
+      SwapFailureKind::CustomProgramError
+    );
+  }
+
+  #[test]
+  fn slippage_codes_are_per_pool() {
+    let program_id = RAYDIUM_CONSTANTS.amm_program;
+    assert_eq!(
+      classify_swap_failure(
+        &Pools::RaydiumAmmV4,
+        &custom_error(RAYDIUM_AMMV4_EXCEEDED_SLIPPAGE_ERROR_CODE),
+        Some(&program_id)
+      ),
+      SwapFailureKind::SlippageExceeded
+    );
+    assert_eq!(
+      classify_swap_failure(
+        &Pools::RaydiumCpmm,
+        &custom_error(RAYDIUM_AMMV4_EXCEEDED_SLIPPAGE_ERROR_CODE),
+        Some(&program_id)
+      ),
+      SwapFailureKind::CustomProgramError
+    );
+  }
+}
@@ -0,0 +1,35 @@
+use crate::constants::compute_budget::COMPUTE_BUDGET_PROGRAM_ID;
+use crate::constants::compute_budget::DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT;
+use crate::constants::compute_budget::MAX_COMPUTE_UNIT_LIMIT;
+use crate::constants::compute_budget::SET_COMPUTE_UNIT_LIMIT_TAG;
+use crate::constants::compute_budget::SET_COMPUTE_UNIT_PRICE_TAG;
+use solana_central::Instruction;
+
+/// Decode the compute unit limit and compute unit price (micro-lamports per compute unit) a
+/// transaction requested from its top level ComputeBudget instructions. Without a
+/// `SetComputeUnitLimit` the runtime default of 200k per non compute budget instruction is used,
+/// builtin programs getting a smaller reservation is not modeled. Without a `SetComputeUnitPrice`
+/// the price is 0.
+pub fn decode_compute_budget(top_level_instructions: &[Instruction]) -> (u32, u64) {
+  let mut compute_unit_limit = None;
+  let mut compute_unit_price = 0;
+  let mut num_non_compute_budget_instructions = 0;
+  for instruction in top_level_instructions {
+    if instruction.tx_account_keys[instruction.program_id_index as usize]
+      != COMPUTE_BUDGET_PROGRAM_ID
+    {
+      num_non_compute_budget_instructions += 1;
+      continue;
+    }
+    let data = instruction.data;
+    if data.len() >= 5 && data[0] == SET_COMPUTE_UNIT_LIMIT_TAG {
+      compute_unit_limit = Some(u32::from_le_bytes(data[1..5].try_into().unwrap()));
+    } else if data.len() >= 9 && data[0] == SET_COMPUTE_UNIT_PRICE_TAG {
+      compute_unit_price = u64::from_le_bytes(data[1..9].try_into().unwrap());
+    }
+  }
+  let compute_unit_limit = compute_unit_limit
+    .unwrap_or(DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT * num_non_compute_budget_instructions)
+    .min(MAX_COMPUTE_UNIT_LIMIT);
+  (compute_unit_limit, compute_unit_price)
+}
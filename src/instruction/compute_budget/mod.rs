@@ -0,0 +1 @@
+pub mod decode_compute_budget;
@@ -0,0 +1,106 @@
+use crate::constants::discriminators::ANCHOR_EVENT_CPI_TAG;
+use crate::constants::known_instructions::PF_KNOWN_INSTRUCTIONS;
+use crate::constants::known_instructions::PUMPSWAP_KNOWN_INSTRUCTIONS;
+use crate::constants::known_instructions::RAYDIUM_AMMV4_KNOWN_INSTRUCTION_TAGS;
+use crate::constants::known_instructions::RAYDIUM_CPMM_KNOWN_INSTRUCTIONS;
+use crate::constants::known_instructions::RAYDIUM_LAUNCHPAD_KNOWN_INSTRUCTIONS;
+use solana_central::Instruction;
+use solana_central::constants::PUMP_CONSTANTS;
+use solana_central::constants::RAYDIUM_CONSTANTS;
+
+/// Determine if an instruction classified as `InstructionType::None` belongs to a program whose
+/// swaps or token creations are decoded but is not one of its known instructions, so it is
+/// reported as an `UnrecognizedInstruction` instead of being dropped. Known instructions that
+/// aren't decoded (deposits, withdrawals, pool creation, migrations, fee collection) and self CPI
+/// events, which are consumed by the instruction that emitted them, are left out.
+pub fn is_unhandled_supported_instruction(instruction: &Instruction) -> bool {
+  let Some(program_id) = instruction
+    .tx_account_keys
+    .get(instruction.program_id_index as usize)
+  else {
+    return false;
+  };
+  let data = instruction.data;
+  if *program_id == RAYDIUM_CONSTANTS.amm_program {
+    return data
+      .first()
+      .is_none_or(|tag| !RAYDIUM_AMMV4_KNOWN_INSTRUCTION_TAGS.contains(tag));
+  }
+  let known_instructions = if *program_id == RAYDIUM_CONSTANTS.cpmm_program {
+    RAYDIUM_CPMM_KNOWN_INSTRUCTIONS
+  } else if *program_id == RAYDIUM_CONSTANTS.launchpad_program {
+    RAYDIUM_LAUNCHPAD_KNOWN_INSTRUCTIONS
+  } else if *program_id == PUMP_CONSTANTS.pump_swap_program {
+    PUMPSWAP_KNOWN_INSTRUCTIONS
+  } else if *program_id == PUMP_CONSTANTS.bonding_curve_program {
+    PF_KNOWN_INSTRUCTIONS
+  } else {
+    return false;
+  };
+  !data.starts_with(&ANCHOR_EVENT_CPI_TAG)
+    && data.get(0..8).is_none_or(|discriminator| {
+      !known_instructions
+        .iter()
+        .any(|known| known == discriminator)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use solana_sdk::pubkey::Pubkey;
+
+  /// Whether an instruction of `program_id` without accounts is unhandled
+  fn is_unhandled(program_id: Pubkey, data: Vec<u8>) -> bool {
+    let account_keys = vec![program_id];
+    let accounts = Vec::new();
+    is_unhandled_supported_instruction(&Instruction {
+      tx_account_keys: &account_keys,
+      accounts: &accounts,
+      data: &data,
+      program_id_index: 0,
+    })
+  }
+
+  #[test]
+  fn unknown_instructions_of_supported_programs_are_unhandled() {
+    for program_id in [
+      RAYDIUM_CONSTANTS.cpmm_program,
+      RAYDIUM_CONSTANTS.launchpad_program,
+      PUMP_CONSTANTS.pump_swap_program,
+      PUMP_CONSTANTS.bonding_curve_program,
+    ] {
+      assert!(is_unhandled(program_id, vec![1, 2, 3, 4, 5, 6, 7, 8]));
+      assert!(is_unhandled(program_id, vec![1, 2, 3]));
+    }
+    assert!(is_unhandled(RAYDIUM_CONSTANTS.amm_program, vec![200, 1, 2]));
+    assert!(is_unhandled(RAYDIUM_CONSTANTS.amm_program, Vec::new()));
+  }
+
+  #[test]
+  fn known_instructions_are_not_unhandled() {
+    // AMMv4 deposit
+    assert!(!is_unhandled(RAYDIUM_CONSTANTS.amm_program, vec![3, 0, 0]));
+    // Cpmm withdraw
+    let mut data = vec![183, 18, 70, 156, 148, 109, 161, 34];
+    data.extend_from_slice(&[0; 24]);
+    assert!(!is_unhandled(RAYDIUM_CONSTANTS.cpmm_program, data));
+    // Launchpad migrate_to_cpswap
+    let data = vec![136, 92, 200, 103, 28, 218, 144, 140];
+    assert!(!is_unhandled(RAYDIUM_CONSTANTS.launchpad_program, data));
+    // Pumpswap create_pool
+    let data = vec![233, 146, 209, 142, 207, 104, 64, 188];
+    assert!(!is_unhandled(PUMP_CONSTANTS.pump_swap_program, data));
+    // Pumpfun collect_creator_fee
+    let data = vec![20, 22, 86, 123, 198, 28, 219, 132];
+    assert!(!is_unhandled(PUMP_CONSTANTS.bonding_curve_program, data));
+  }
+
+  #[test]
+  fn events_and_other_programs_are_not_unhandled() {
+    let mut event_data = ANCHOR_EVENT_CPI_TAG.to_vec();
+    event_data.extend_from_slice(&[9; 8]);
+    assert!(!is_unhandled(PUMP_CONSTANTS.pump_swap_program, event_data));
+    assert!(!is_unhandled(Pubkey::new_unique(), vec![1, 2, 3]));
+  }
+}
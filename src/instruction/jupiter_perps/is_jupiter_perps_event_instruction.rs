@@ -0,0 +1,26 @@
+use crate::constants::discriminators::ANCHOR_EVENT_CPI_TAG;
+use crate::constants::jupiter_perps::JUPITER_PERPS_DECREASE_POSITION_EVENT_DISCRIMINATOR;
+use crate::constants::jupiter_perps::JUPITER_PERPS_INCREASE_POSITION_EVENT_DISCRIMINATOR;
+use crate::constants::jupiter_perps::JUPITER_PERPS_INSTANT_DECREASE_POSITION_EVENT_DISCRIMINATOR;
+use crate::constants::jupiter_perps::JUPITER_PERPS_INSTANT_INCREASE_POSITION_EVENT_DISCRIMINATOR;
+use crate::constants::jupiter_perps::JUPITER_PERPS_LIQUIDATE_FULL_POSITION_EVENT_DISCRIMINATOR;
+use crate::constants::jupiter_perps::JUPITER_PERPS_PROGRAM_ID;
+use solana_central::Instruction;
+
+/// Determine if an instruction is a Jupiter Perps position event emitted with self CPI. Checks the
+/// program ID, the Anchor event CPI prefix and the event discriminator.
+pub fn is_jupiter_perps_event_instruction(instruction: &Instruction) -> bool {
+  if instruction.data.len() < 16
+    || instruction.data[0..8] != ANCHOR_EVENT_CPI_TAG
+    || instruction.tx_account_keys[instruction.program_id_index as usize]
+      != JUPITER_PERPS_PROGRAM_ID
+  {
+    return false;
+  }
+  let discriminator = &instruction.data[8..16];
+  discriminator == JUPITER_PERPS_INCREASE_POSITION_EVENT_DISCRIMINATOR
+    || discriminator == JUPITER_PERPS_DECREASE_POSITION_EVENT_DISCRIMINATOR
+    || discriminator == JUPITER_PERPS_LIQUIDATE_FULL_POSITION_EVENT_DISCRIMINATOR
+    || discriminator == JUPITER_PERPS_INSTANT_INCREASE_POSITION_EVENT_DISCRIMINATOR
+    || discriminator == JUPITER_PERPS_INSTANT_DECREASE_POSITION_EVENT_DISCRIMINATOR
+}
@@ -0,0 +1,2 @@
+pub mod is_jupiter_perps_event_instruction;
+pub mod process_jupiter_perps_event_instruction;
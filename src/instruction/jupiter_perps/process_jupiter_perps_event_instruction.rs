@@ -0,0 +1,392 @@
+use crate::constants::jupiter_perps::JUPITER_PERPS_DECREASE_POSITION_EVENT_DISCRIMINATOR;
+use crate::constants::jupiter_perps::JUPITER_PERPS_INCREASE_POSITION_EVENT_DISCRIMINATOR;
+use crate::constants::jupiter_perps::JUPITER_PERPS_INSTANT_DECREASE_POSITION_EVENT_DISCRIMINATOR;
+use crate::constants::jupiter_perps::JUPITER_PERPS_INSTANT_INCREASE_POSITION_EVENT_DISCRIMINATOR;
+use crate::constants::jupiter_perps::JUPITER_PERPS_LIQUIDATE_FULL_POSITION_EVENT_DISCRIMINATOR;
+use crate::protocol_idls::jupiter_perps::jupiter_perps_decrease_position_event_idl::JupiterPerpsDecreasePositionEventIdl;
+use crate::protocol_idls::jupiter_perps::jupiter_perps_increase_position_event_idl::JupiterPerpsIncreasePositionEventIdl;
+use crate::protocol_idls::jupiter_perps::jupiter_perps_instant_decrease_position_event_idl::JupiterPerpsInstantDecreasePositionEventIdl;
+use crate::protocol_idls::jupiter_perps::jupiter_perps_instant_increase_position_event_idl::JupiterPerpsInstantIncreasePositionEventIdl;
+use crate::protocol_idls::jupiter_perps::jupiter_perps_liquidate_full_position_event_idl::JupiterPerpsLiquidateFullPositionEventIdl;
+use crate::types::perp_event::PerpEvent;
+use crate::types::perp_event_kind::PerpEventKind;
+use crate::types::perp_side::PerpSide;
+use borsh::BorshDeserialize;
+use solana_central::Instruction;
+use solana_sdk::signature::Signature;
+
+/// Process a Jupiter Perps event instruction and create a PerpEvent. Assumes the instruction has
+/// been validated with `is_jupiter_perps_event_instruction`. Only the known leading fields of the
+/// event are decoded so fields appended by program upgrades don't break decoding. Returns `None` if
+/// the event can't be decoded or has no position side.
+pub fn process_jupiter_perps_event_instruction(
+  instruction: &Instruction,
+  block_time: u64,
+  slot: u64,
+  index: u64,
+  atomic_instruction_index: u8,
+  signature: &Signature,
+) -> Option<PerpEvent> {
+  let discriminator = instruction.data.get(8..16)?;
+  // Skip the event CPI tag and discriminator
+  let mut body = &instruction.data[16..];
+  // Jupiter Perps sides are None = 0, Long = 1, Short = 2
+  let to_perp_side = |position_side: u8| match position_side {
+    1 => Some(PerpSide::Long),
+    2 => Some(PerpSide::Short),
+    _ => None,
+  };
+  let pnl = |has_profit: bool, pnl_delta: u64| {
+    if has_profit {
+      pnl_delta as i128
+    } else {
+      -(pnl_delta as i128)
+    }
+  };
+
+  let kind;
+  let position;
+  let owner;
+  let custody;
+  let collateral_custody;
+  let side;
+  let size_usd;
+  let size_usd_delta;
+  let collateral_usd_delta;
+  let price;
+  let fee_usd;
+  let pnl_usd;
+  if discriminator == JUPITER_PERPS_INCREASE_POSITION_EVENT_DISCRIMINATOR {
+    let event = JupiterPerpsIncreasePositionEventIdl::deserialize(&mut body).ok()?;
+    kind = PerpEventKind::IncreasePosition;
+    position = event.position_key;
+    owner = Some(event.owner);
+    custody = event.position_custody;
+    collateral_custody = event.position_collateral_custody;
+    side = to_perp_side(event.position_side)?;
+    size_usd = event.position_size_usd;
+    size_usd_delta = event.size_usd_delta;
+    collateral_usd_delta = Some(event.collateral_usd_delta);
+    price = event.price;
+    fee_usd = event.fee_usd;
+    pnl_usd = None;
+  } else if discriminator == JUPITER_PERPS_DECREASE_POSITION_EVENT_DISCRIMINATOR {
+    let event = JupiterPerpsDecreasePositionEventIdl::deserialize(&mut body).ok()?;
+    kind = PerpEventKind::DecreasePosition;
+    position = event.position_key;
+    owner = Some(event.owner);
+    custody = event.position_custody;
+    collateral_custody = event.position_collateral_custody;
+    side = to_perp_side(event.position_side)?;
+    size_usd = event.position_size_usd;
+    size_usd_delta = event.size_usd_delta;
+    collateral_usd_delta = Some(event.collateral_usd_delta);
+    price = event.price;
+    fee_usd = event.fee_usd;
+    pnl_usd = Some(pnl(event.has_profit, event.pnl_delta));
+  } else if discriminator == JUPITER_PERPS_LIQUIDATE_FULL_POSITION_EVENT_DISCRIMINATOR {
+    let event = JupiterPerpsLiquidateFullPositionEventIdl::deserialize(&mut body).ok()?;
+    kind = PerpEventKind::LiquidateFullPosition;
+    position = event.position_key;
+    owner = None;
+    custody = event.position_custody;
+    collateral_custody = event.position_collateral_custody;
+    side = to_perp_side(event.position_side)?;
+    // The whole position is closed
+    size_usd = 0;
+    size_usd_delta = event.position_size_usd;
+    collateral_usd_delta = None;
+    price = event.price;
+    fee_usd = event.fee_usd.saturating_add(event.liquidation_fee_usd);
+    pnl_usd = Some(pnl(event.has_profit, event.pnl_delta));
+  } else if discriminator == JUPITER_PERPS_INSTANT_INCREASE_POSITION_EVENT_DISCRIMINATOR {
+    let event = JupiterPerpsInstantIncreasePositionEventIdl::deserialize(&mut body).ok()?;
+    kind = PerpEventKind::InstantIncreasePosition;
+    position = event.position_key;
+    owner = Some(event.owner);
+    custody = event.position_custody;
+    collateral_custody = event.position_collateral_custody;
+    side = to_perp_side(event.position_side)?;
+    size_usd = event.position_size_usd;
+    size_usd_delta = event.size_usd_delta;
+    collateral_usd_delta = Some(event.collateral_usd_delta);
+    price = event.price;
+    fee_usd = event.fee_usd;
+    pnl_usd = None;
+  } else if discriminator == JUPITER_PERPS_INSTANT_DECREASE_POSITION_EVENT_DISCRIMINATOR {
+    let event = JupiterPerpsInstantDecreasePositionEventIdl::deserialize(&mut body).ok()?;
+    kind = PerpEventKind::InstantDecreasePosition;
+    position = event.position_key;
+    owner = Some(event.owner);
+    custody = event.position_custody;
+    collateral_custody = event.position_collateral_custody;
+    side = to_perp_side(event.position_side)?;
+    size_usd = event.position_size_usd;
+    size_usd_delta = event.size_usd_delta;
+    collateral_usd_delta = Some(event.collateral_usd_delta);
+    price = event.price;
+    fee_usd = event.fee_usd;
+    pnl_usd = Some(pnl(event.has_profit, event.pnl_delta));
+  } else {
+    return None;
+  }
+
+  Some(PerpEvent {
+    kind,
+    signature: signature.clone(),
+    block_time,
+    slot,
+    index,
+    atomic_instruction_index,
+    position,
+    owner,
+    custody,
+    collateral_custody,
+    side,
+    size_usd,
+    size_usd_delta,
+    collateral_usd_delta,
+    price,
+    fee_usd,
+    pnl_usd,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::constants::discriminators::ANCHOR_EVENT_CPI_TAG;
+  use crate::constants::jupiter_perps::JUPITER_PERPS_PROGRAM_ID;
+  use solana_sdk::pubkey::Pubkey;
+
+  struct Keys {
+    position: Pubkey,
+    custody: Pubkey,
+    collateral_custody: Pubkey,
+    owner: Pubkey,
+  }
+
+  impl Keys {
+    fn new() -> Self {
+      Keys {
+        position: Pubkey::new_unique(),
+        custody: Pubkey::new_unique(),
+        collateral_custody: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+      }
+    }
+
+    /// Fields shared by every event up to and including `position_size_usd`
+    fn position_prefix(&self, body: &mut Vec<u8>, side: u8, size_usd: u64) {
+      body.extend_from_slice(self.position.as_ref());
+      body.push(side);
+      body.extend_from_slice(self.custody.as_ref());
+      body.extend_from_slice(self.collateral_custody.as_ref());
+      body.extend_from_slice(&size_usd.to_le_bytes());
+    }
+  }
+
+  fn request_accounts(body: &mut Vec<u8>) {
+    // position_request_key, position_request_mint
+    body.extend_from_slice(Pubkey::new_unique().as_ref());
+    body.extend_from_slice(Pubkey::new_unique().as_ref());
+    // position_request_change, position_request_type, pool
+    body.push(1);
+    body.push(2);
+    body.extend_from_slice(Pubkey::new_unique().as_ref());
+  }
+
+  fn decode(discriminator: [u8; 8], body: &[u8]) -> Option<PerpEvent> {
+    let account_keys = vec![JUPITER_PERPS_PROGRAM_ID];
+    let accounts = Vec::new();
+    let mut data = ANCHOR_EVENT_CPI_TAG.to_vec();
+    data.extend_from_slice(&discriminator);
+    data.extend_from_slice(body);
+    let instruction = Instruction {
+      tx_account_keys: &account_keys,
+      accounts: &accounts,
+      data: &data,
+      program_id_index: 0,
+    };
+    process_jupiter_perps_event_instruction(&instruction, 1, 2, 3, 4, &Signature::new_unique())
+  }
+
+  fn increase_body(keys: &Keys, side: u8, instant: bool) -> Vec<u8> {
+    let mut body = Vec::new();
+    keys.position_prefix(&mut body, side, 5_000_000);
+    // position_mint
+    body.extend_from_slice(Pubkey::new_unique().as_ref());
+    if !instant {
+      request_accounts(&mut body);
+    }
+    body.extend_from_slice(keys.owner.as_ref());
+    // size_usd_delta, collateral_usd_delta, collateral_token_delta, price
+    for value in [2_000_000u64, 1_000_000, 7, 150_000_000] {
+      body.extend_from_slice(&value.to_le_bytes());
+    }
+    // price_slippage
+    if !instant {
+      body.push(1);
+    }
+    body.extend_from_slice(&151_000_000u64.to_le_bytes());
+    // fee_token, fee_usd
+    body.extend_from_slice(&3u64.to_le_bytes());
+    body.extend_from_slice(&1_200u64.to_le_bytes());
+    body
+  }
+
+  fn decrease_body(keys: &Keys, side: u8, instant: bool) -> Vec<u8> {
+    let mut body = Vec::new();
+    keys.position_prefix(&mut body, side, 3_000_000);
+    // position_mint
+    body.extend_from_slice(Pubkey::new_unique().as_ref());
+    if !instant {
+      request_accounts(&mut body);
+    }
+    body.extend_from_slice(keys.owner.as_ref());
+    // size_usd_delta, collateral_usd_delta
+    body.extend_from_slice(&2_000_000u64.to_le_bytes());
+    body.extend_from_slice(&500_000u64.to_le_bytes());
+    // has_profit, pnl_delta, transfer_amount_usd, transfer_token
+    body.push(0);
+    body.extend_from_slice(&40_000u64.to_le_bytes());
+    body.extend_from_slice(&460_000u64.to_le_bytes());
+    body.push(1);
+    body.extend_from_slice(&9u64.to_le_bytes());
+    // price, price_slippage
+    body.extend_from_slice(&149_000_000u64.to_le_bytes());
+    if !instant {
+      body.push(0);
+    } else {
+      body.extend_from_slice(&148_000_000u64.to_le_bytes());
+    }
+    // fee_usd
+    body.extend_from_slice(&1_000u64.to_le_bytes());
+    body
+  }
+
+  fn assert_increase(event: PerpEvent, keys: &Keys, kind: PerpEventKind) {
+    assert_eq!(event.kind, kind);
+    assert_eq!(event.position, keys.position);
+    assert_eq!(event.owner, Some(keys.owner));
+    assert_eq!(event.custody, keys.custody);
+    assert_eq!(event.collateral_custody, keys.collateral_custody);
+    assert_eq!(event.side, PerpSide::Long);
+    assert_eq!(event.size_usd, 5_000_000);
+    assert_eq!(event.size_usd_delta, 2_000_000);
+    assert_eq!(event.collateral_usd_delta, Some(1_000_000));
+    assert_eq!(event.price, 150_000_000);
+    assert_eq!(event.fee_usd, 1_200);
+    assert_eq!(event.pnl_usd, None);
+    assert_eq!(event.atomic_instruction_index, 4);
+  }
+
+  fn assert_decrease(event: PerpEvent, keys: &Keys, kind: PerpEventKind) {
+    assert_eq!(event.kind, kind);
+    assert_eq!(event.position, keys.position);
+    assert_eq!(event.owner, Some(keys.owner));
+    assert_eq!(event.side, PerpSide::Short);
+    assert_eq!(event.size_usd, 3_000_000);
+    assert_eq!(event.size_usd_delta, 2_000_000);
+    assert_eq!(event.collateral_usd_delta, Some(500_000));
+    assert_eq!(event.price, 149_000_000);
+    assert_eq!(event.fee_usd, 1_000);
+    assert_eq!(event.pnl_usd, Some(-40_000));
+  }
+
+  #[test]
+  fn decodes_increase_position_event() {
+    let keys = Keys::new();
+    let body = increase_body(&keys, 1, false);
+    let event = decode(JUPITER_PERPS_INCREASE_POSITION_EVENT_DISCRIMINATOR, &body).unwrap();
+    assert_increase(event, &keys, PerpEventKind::IncreasePosition);
+  }
+
+  #[test]
+  fn decodes_instant_increase_position_event() {
+    let keys = Keys::new();
+    let body = increase_body(&keys, 1, true);
+    let event = decode(
+      JUPITER_PERPS_INSTANT_INCREASE_POSITION_EVENT_DISCRIMINATOR,
+      &body,
+    )
+    .unwrap();
+    assert_increase(event, &keys, PerpEventKind::InstantIncreasePosition);
+  }
+
+  #[test]
+  fn decodes_decrease_position_event() {
+    let keys = Keys::new();
+    let body = decrease_body(&keys, 2, false);
+    let event = decode(JUPITER_PERPS_DECREASE_POSITION_EVENT_DISCRIMINATOR, &body).unwrap();
+    assert_decrease(event, &keys, PerpEventKind::DecreasePosition);
+  }
+
+  #[test]
+  fn decodes_instant_decrease_position_event() {
+    let keys = Keys::new();
+    let body = decrease_body(&keys, 2, true);
+    let event = decode(
+      JUPITER_PERPS_INSTANT_DECREASE_POSITION_EVENT_DISCRIMINATOR,
+      &body,
+    )
+    .unwrap();
+    assert_decrease(event, &keys, PerpEventKind::InstantDecreasePosition);
+  }
+
+  #[test]
+  fn decodes_liquidate_full_position_event() {
+    let keys = Keys::new();
+    let mut body = Vec::new();
+    body.extend_from_slice(keys.position.as_ref());
+    body.push(1);
+    body.extend_from_slice(keys.custody.as_ref());
+    body.extend_from_slice(keys.collateral_custody.as_ref());
+    // position_collateral_mint, position_mint
+    body.extend_from_slice(Pubkey::new_unique().as_ref());
+    body.extend_from_slice(Pubkey::new_unique().as_ref());
+    // position_size_usd, has_profit, pnl_delta
+    body.extend_from_slice(&8_000_000u64.to_le_bytes());
+    body.push(0);
+    body.extend_from_slice(&900_000u64.to_le_bytes());
+    // transfer_amount_usd, transfer_token, price, fee_usd, liquidation_fee_usd
+    for value in [0u64, 0, 120_000_000, 2_000, 5_000] {
+      body.extend_from_slice(&value.to_le_bytes());
+    }
+    let event = decode(
+      JUPITER_PERPS_LIQUIDATE_FULL_POSITION_EVENT_DISCRIMINATOR,
+      &body,
+    )
+    .unwrap();
+    assert_eq!(event.kind, PerpEventKind::LiquidateFullPosition);
+    assert_eq!(event.position, keys.position);
+    assert_eq!(event.owner, None);
+    assert_eq!(event.side, PerpSide::Long);
+    assert_eq!(event.size_usd, 0);
+    assert_eq!(event.size_usd_delta, 8_000_000);
+    assert_eq!(event.price, 120_000_000);
+    assert_eq!(event.fee_usd, 7_000);
+    assert_eq!(event.pnl_usd, Some(-900_000));
+  }
+
+  #[test]
+  fn side_none_is_rejected() {
+    let keys = Keys::new();
+    let body = increase_body(&keys, 0, false);
+    assert!(decode(JUPITER_PERPS_INCREASE_POSITION_EVENT_DISCRIMINATOR, &body).is_none());
+  }
+
+  #[test]
+  fn truncated_event_and_unknown_discriminator_are_none() {
+    let keys = Keys::new();
+    let body = increase_body(&keys, 1, false);
+    assert!(
+      decode(
+        JUPITER_PERPS_INCREASE_POSITION_EVENT_DISCRIMINATOR,
+        &body[..100]
+      )
+      .is_none()
+    );
+    assert!(decode([0; 8], &body).is_none());
+  }
+}
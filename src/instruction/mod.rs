@@ -1,4 +1,15 @@
+pub mod compute_budget;
+pub mod jupiter_perps;
 pub mod pumpfun;
 pub mod pumpswap;
 pub mod raydium;
-pub mod classify_instruction;
\ No newline at end of file
+pub mod router;
+pub mod system;
+pub mod token;
+pub mod classify_instruction;
+pub mod classify_pending_instruction;
+pub mod classify_swap_failure;
+pub mod is_unhandled_supported_instruction;
+pub mod process_pending_swap_instruction;
+pub mod process_unrecognized_instruction;
+pub mod resolve_event_layout;
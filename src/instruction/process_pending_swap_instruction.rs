@@ -0,0 +1,111 @@
+use crate::instruction::pumpfun::decode_pf_bonding_curve_swap_intent::decode_pf_bonding_curve_swap_intent;
+use crate::instruction::pumpswap::decode_pumpswap_swap_intent::decode_pumpswap_swap_intent;
+use crate::instruction::raydium::decode_raydium_ammv4_swap_intent::decode_raydium_ammv4_swap_intent;
+use crate::instruction::raydium::decode_raydium_cpmm_swap_intent::decode_raydium_cpmm_swap_intent;
+use crate::instruction::raydium::decode_raydium_launchpad_swap_intent::decode_raydium_launchpad_swap_intent;
+use crate::types::instruction_type::InstructionType;
+use crate::types::pending_swap::PendingSwap;
+use solana_central::Instruction;
+use solana_central::Pools;
+use solana_central::SwapDirection;
+use solana_central::constants::TOKENS;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashSet;
+
+/// Process a swap instruction from a transaction without status meta and create a PendingSwap.
+/// Only uses the instruction accounts and arguments. `instruction_type` and `swap_direction` are
+/// the classification of the instruction, `PfBondingCurveSwap` here means a Pumpfun `buy`/`sell`
+/// instruction rather than the event. Returns `None` for instruction types that are not swaps and
+/// for instructions with fewer accounts than the swap layout.
+pub fn process_pending_swap_instruction(
+  instruction: &Instruction,
+  instruction_type: InstructionType,
+  swap_direction: SwapDirection,
+  slot: u64,
+  instruction_index: u8,
+  signers: &HashSet<Pubkey>,
+  signature: &Signature,
+) -> Option<PendingSwap> {
+  let account = |i: usize| {
+    let account_index = *instruction.accounts.get(i)?;
+    instruction
+      .tx_account_keys
+      .get(account_index as usize)
+      .copied()
+  };
+
+  let pool;
+  let direction;
+  let market_address;
+  let token_a_address;
+  let token_b_address;
+  let intent;
+  match instruction_type {
+    InstructionType::RaydiumAmmV4Swap => {
+      pool = Pools::RaydiumAmmV4;
+      // Direction is decided by which vault receives tokens, needs token balances to know
+      direction = None;
+      market_address = account(1)?;
+      token_a_address = None;
+      token_b_address = None;
+      intent = decode_raydium_ammv4_swap_intent(instruction);
+    }
+    InstructionType::RaydiumCpmmSwap => {
+      pool = Pools::RaydiumCpmm;
+      market_address = account(3)?;
+      let input_token_mint = account(10)?;
+      let output_token_mint = account(11)?;
+      // Same lexiographic ordering as `process_raydium_cpmm_swap_instruction`
+      if input_token_mint < output_token_mint {
+        direction = Some(SwapDirection::AToB);
+        token_a_address = Some(input_token_mint);
+        token_b_address = Some(output_token_mint);
+      } else {
+        direction = Some(SwapDirection::BToA);
+        token_a_address = Some(output_token_mint);
+        token_b_address = Some(input_token_mint);
+      }
+      intent = decode_raydium_cpmm_swap_intent(instruction);
+    }
+    InstructionType::RaydiumLaunchpadSwap => {
+      pool = Pools::RaydiumLaunchpad;
+      direction = Some(swap_direction);
+      market_address = account(4)?;
+      token_a_address = Some(account(9)?);
+      token_b_address = Some(account(10)?);
+      intent = decode_raydium_launchpad_swap_intent(instruction);
+    }
+    InstructionType::PumpswapSwap => {
+      pool = Pools::PumpswapAmm;
+      direction = Some(swap_direction);
+      market_address = account(0)?;
+      token_a_address = Some(account(3)?);
+      token_b_address = Some(account(4)?);
+      intent = decode_pumpswap_swap_intent(instruction);
+    }
+    InstructionType::PfBondingCurveSwap => {
+      pool = Pools::PfBondingCurve;
+      direction = Some(swap_direction);
+      // Bonding curve account is passed right after the mint
+      market_address = account(3)?;
+      token_a_address = Some(account(2)?);
+      token_b_address = Some(TOKENS.wsol);
+      intent = decode_pf_bonding_curve_swap_intent(instruction);
+    }
+    _ => return None,
+  }
+
+  Some(PendingSwap {
+    pool,
+    direction,
+    market_address,
+    token_a_address,
+    token_b_address,
+    intent,
+    slot,
+    instruction_index,
+    signature: signature.clone(),
+    signers: signers.clone(),
+  })
+}
@@ -0,0 +1,37 @@
+use crate::constants::discriminators::ANCHOR_EVENT_CPI_TAG;
+use crate::types::unrecognized_instruction::UnrecognizedInstruction;
+use solana_central::Instruction;
+use solana_sdk::signature::Signature;
+
+/// Create an UnrecognizedInstruction from an instruction a decoder could not handle.
+pub fn process_unrecognized_instruction(
+  instruction: &Instruction,
+  block_time: u64,
+  slot: u64,
+  index: u64,
+  atomic_instruction_index: u8,
+  signature: &Signature,
+) -> UnrecognizedInstruction {
+  let discriminator =
+    if instruction.data.len() >= 16 && instruction.data[0..8] == ANCHOR_EVENT_CPI_TAG {
+      &instruction.data[8..16]
+    } else {
+      &instruction.data[..instruction.data.len().min(8)]
+    };
+  UnrecognizedInstruction {
+    signature: *signature,
+    block_time,
+    slot,
+    index,
+    atomic_instruction_index,
+    program_id: instruction
+      .tx_account_keys
+      .get(instruction.program_id_index as usize)
+      .copied()
+      .unwrap_or_default(),
+    discriminator: discriminator.to_vec(),
+    data_len: instruction.data.len(),
+    data: instruction.data.to_vec(),
+    num_accounts: instruction.accounts.len(),
+  }
+}
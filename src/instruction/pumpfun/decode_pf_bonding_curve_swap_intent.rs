@@ -0,0 +1,28 @@
+use crate::types::swap_intent::SwapIntent;
+use crate::types::swap_mode::SwapMode;
+use solana_central::Instruction;
+use solana_central::constants::PUMP_CONSTANTS;
+
+/// Decode the swap arguments of a Pumpfun bonding curve swap instruction. Assumes the instruction
+/// has been validated with `is_pf_bonding_curve_swap_instruction`.
+pub fn decode_pf_bonding_curve_swap_intent(instruction: &Instruction) -> SwapIntent {
+  let first_arg = u64::from_le_bytes(instruction.data[8..16].try_into().unwrap());
+  let second_arg = u64::from_le_bytes(instruction.data[16..24].try_into().unwrap());
+  /*
+  buy: token amount out, max_sol_cost
+  sell: token amount in, min_sol_output
+  buy_exact_sol_in: spendable_sol_in, min_tokens_out
+  */
+  // Only plain buy fixes the output amount
+  let mode = if instruction.data[0..8] == PUMP_CONSTANTS.buy_instruction_discriminator {
+    SwapMode::ExactOut
+  } else {
+    SwapMode::ExactIn
+  };
+  SwapIntent {
+    mode,
+    specified_amount: first_arg,
+    limit_amount: second_arg,
+    slippage_tolerance_bps: None,
+  }
+}
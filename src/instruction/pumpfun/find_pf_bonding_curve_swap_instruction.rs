@@ -0,0 +1,21 @@
+use crate::instruction::pumpfun::is_pf_bonding_curve_swap_instruction::is_pf_bonding_curve_swap_instruction;
+use solana_central::Instruction;
+
+/// Find the Pumpfun `buy`/`sell` instruction that emitted the bonding curve event at `event_index`
+/// in `inner_instructions`. The event is emitted from within the swap, so the swap is the closest
+/// preceding Pumpfun swap instruction, or the top level instruction if it was called directly.
+pub fn find_pf_bonding_curve_swap_instruction<'a, 'b>(
+  inner_instructions: &'a [Instruction<'b>],
+  event_index: usize,
+  top_level_instruction: &'a Instruction<'b>,
+) -> Option<&'a Instruction<'b>> {
+  for i in (0..event_index).rev() {
+    if is_pf_bonding_curve_swap_instruction(&inner_instructions[i]).0 {
+      return Some(&inner_instructions[i]);
+    }
+  }
+  if is_pf_bonding_curve_swap_instruction(top_level_instruction).0 {
+    return Some(top_level_instruction);
+  }
+  None
+}
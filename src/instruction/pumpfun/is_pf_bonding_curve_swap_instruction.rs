@@ -0,0 +1,34 @@
+use crate::constants::discriminators::PF_BUY_EXACT_SOL_IN_DISCRIMINATOR;
+use solana_central::Instruction;
+use solana_central::SwapDirection;
+use solana_central::constants::PUMP_CONSTANTS;
+
+/// Determine if a Solana instruction is a Pumpfun bonding curve `buy`, `sell` or
+/// `buy_exact_sol_in` instruction. These carry the user's swap arguments, the swap outcome is in
+/// the event instruction emitted by them. Returns the swap direction identified by the
+/// discriminator.
+pub fn is_pf_bonding_curve_swap_instruction(instruction: &Instruction) -> (bool, SwapDirection) {
+  // Discriminator and two u64 arguments
+  if instruction.data.len() < 24 {
+    return (false, SwapDirection::AToB);
+  }
+  // Mint and bonding curve are the third and fourth accounts
+  if instruction.accounts.len() < 4 {
+    return (false, SwapDirection::AToB);
+  }
+  if instruction.tx_account_keys[instruction.program_id_index as usize]
+    != PUMP_CONSTANTS.bonding_curve_program
+  {
+    return (false, SwapDirection::AToB);
+  }
+  let discriminator = &instruction.data[0..8];
+  if discriminator == PUMP_CONSTANTS.buy_instruction_discriminator
+    || discriminator == PF_BUY_EXACT_SOL_IN_DISCRIMINATOR
+  {
+    return (true, SwapDirection::BToA);
+  } else if discriminator == PUMP_CONSTANTS.sell_instruction_discriminator {
+    return (true, SwapDirection::AToB);
+  } else {
+    return (false, SwapDirection::AToB);
+  }
+}
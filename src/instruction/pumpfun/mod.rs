@@ -1,4 +1,7 @@
+pub mod decode_pf_bonding_curve_swap_intent;
+pub mod find_pf_bonding_curve_swap_instruction;
 pub mod process_pumpfun_event_instruction;
 pub mod is_pumpfun_event_instruction;
 pub mod is_pf_bonding_curve_create_instruction;
-pub mod process_pf_bonding_curve_create_instruction;
\ No newline at end of file
+pub mod is_pf_bonding_curve_swap_instruction;
+pub mod process_pf_bonding_curve_create_instruction;
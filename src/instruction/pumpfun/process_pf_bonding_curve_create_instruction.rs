@@ -4,7 +4,8 @@ use solana_sdk::signature::Signature;
 
 /// Process a Pumpfun bonding curve creation instruction and create a TokenCreation. Extracts token
 /// creation information from the bonding curve create instruction data, including name, symbol,
-/// URI, and metadata fields.
+/// URI, and metadata fields. Returns `None` if the strings run past the end of the data or the
+/// accounts are missing.
 pub fn process_pf_bonding_curve_create_instruction(
   instruction: &Instruction,
   block_time: u64,
@@ -12,33 +13,34 @@ pub fn process_pf_bonding_curve_create_instruction(
   index: u64,
   atomic_instruction_index: u8,
   signature: &Signature,
-) -> TokenCreation {
+) -> Option<TokenCreation> {
   let data = instruction.data;
+  let account = |i: usize| {
+    let account_index = *instruction.accounts.get(i)?;
+    instruction
+      .tx_account_keys
+      .get(account_index as usize)
+      .copied()
+  };
 
   // Skip 8-byte discriminator
   let mut offset = 8;
+  // Parse a string (4 bytes length + UTF-8 bytes) and move past it
+  let mut read_string = || {
+    let len = u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize;
+    offset += 4;
+    let string = String::from_utf8_lossy(data.get(offset..offset.checked_add(len)?)?).to_string();
+    offset += len;
+    Some(string)
+  };
+  let name = read_string()?;
+  let symbol = read_string()?;
+  let uri = read_string()?;
 
-  // Parse name string (4 bytes length + UTF-8 bytes)
-  let name_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-  offset += 4;
-  let name = String::from_utf8_lossy(&data[offset..offset + name_len]).to_string();
-  offset += name_len;
-
-  // Parse symbol string (4 bytes length + UTF-8 bytes)
-  let symbol_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-  offset += 4;
-  let symbol = String::from_utf8_lossy(&data[offset..offset + symbol_len]).to_string();
-  offset += symbol_len;
-
-  // Parse uri string (4 bytes length + UTF-8 bytes)
-  let uri_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-  offset += 4;
-  let uri = String::from_utf8_lossy(&data[offset..offset + uri_len]).to_string();
-
-  TokenCreation {
-    address: instruction.tx_account_keys[instruction.accounts[0] as usize],
-    creator: instruction.tx_account_keys[instruction.accounts[7] as usize],
-    market_address: instruction.tx_account_keys[instruction.accounts[2] as usize],
+  Some(TokenCreation {
+    address: account(0)?,
+    creator: account(7)?,
+    market_address: account(2)?,
     name,
     symbol,
     uri,
@@ -50,5 +52,5 @@ pub fn process_pf_bonding_curve_create_instruction(
     index,
     atomic_instruction_index,
     signature: signature.clone(),
-  }
+  })
 }
@@ -1,4 +1,11 @@
-use borsh::BorshDeserialize;
+use crate::constants::event_layouts::EVENT_LAYOUTS;
+use crate::instruction::pumpfun::decode_pf_bonding_curve_swap_intent::decode_pf_bonding_curve_swap_intent;
+use crate::instruction::resolve_event_layout::resolve_event_layout;
+use crate::types::event_layout::EventLayout;
+use crate::types::swap_details::SwapDetails;
+use crate::types::swap_fees::SwapFees;
+use crate::utilities::compute_slippage_tolerance_bps::compute_slippage_tolerance_bps;
+use crate::utilities::decode_event_prefix::decode_event_prefix;
 use solana_central::Instruction;
 use solana_central::Pools;
 use solana_central::SwapDirection;
@@ -12,46 +19,64 @@ use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use std::collections::HashSet;
 
-/// Process a Pumpfun bonding curve event instruction and create a SwapTx. Pumpfun bonding curve
-/// events contain all information needed to build the SwapTx type. Assumes the instruction has
-/// already been validated as a valid Pumpfun event instruction.
+/// Process a Pumpfun bonding curve event instruction and create a SwapTx with its SwapDetails.
+/// Pumpfun bonding curve events contain all information needed to build the SwapTx type. Assumes
+/// the instruction has already been validated as a valid Pumpfun event instruction. Returns `None`
+/// if the event layout is not in the layout registry or the event can't be decoded with it.
 pub fn process_pumpfun_event_instruction(
   instruction: &Instruction,
+  // The buy/sell instruction that emitted the event, used for the swap arguments
+  swap_instruction: Option<&Instruction>,
   block_time: u64,
   slot: u64,
   index: u64,
   atomic_instruction_index: u8,
   signers: &HashSet<Pubkey>,
   signature: &Signature,
-) -> SwapTx {
+) -> Option<(SwapTx, SwapDetails)> {
   let token_address;
   let is_buy;
   let fee_fraction_lp;
   let sol_amount;
   let total_fee;
+  let protocol_fee;
+  let creator_fee;
+  let creator;
+  let fee_recipient;
+  let track_volume;
   let token_amount;
   let pool_token_a_vault_amount;
   let pool_token_b_vault_amount;
   let virtual_sol_reserves;
   let virtual_token_reserves;
+  let unknown_event_data;
 
   /*
   273: ix name (buy): 2YSPuG5KmLsZmQAsnzgUo1185nvqR8fHUZtaUxZW5ug53717ajgHATggpUEN5UN9HpM4DCQYQKwAXEigUpAhDiqh
   274: ix name (sell): 4kDQCjvGqzMF53MnLaTwCyTN3Rc2E4vDSMYrvwj36XZq7nqETkPiEbiZzwwKbBYxJtyiVECwh3wQoqvPuPtUXhxp
   286: ix name (buy_exact_sol_in): 55P61eQ7N8BGDNLP92H1evgk14uM5zw8FBb44StXwnYthspKzgRdCKXGXL2Mgsisu4SErXLQrS3PeLfppTF9sUAc
 
-  266 is the previous events used that didn't have the strings with ix name on it
+  266 is the previous events used that didn't have the strings with ix name on it. The ix name and
+  anything appended later is kept as unknown event data.
   */
-  if instruction.data.len() == 266
-    || instruction.data.len() == 273
-    || instruction.data.len() == 274
-    || instruction.data.len() == 286
-  {
-    let decoded_layout = PfTradeEventIdlCurrent::try_from_slice(&instruction.data[..266]).unwrap();
+  let layout = resolve_event_layout(
+    &EVENT_LAYOUTS,
+    &PUMP_CONSTANTS.bonding_curve_program,
+    instruction.data,
+    slot,
+  );
+  if layout == Some(EventLayout::PfTradeEventCurrent) {
+    let (decoded_layout, tail) = decode_event_prefix::<PfTradeEventIdlCurrent>(instruction.data)?;
+    unknown_event_data = tail.to_vec();
     token_address = decoded_layout.mint;
     is_buy = decoded_layout.is_buy;
     sol_amount = decoded_layout.sol_amount;
-    total_fee = decoded_layout.creator_fee + decoded_layout.fee;
+    protocol_fee = decoded_layout.fee;
+    creator_fee = decoded_layout.creator_fee;
+    creator = Some(decoded_layout.creator);
+    fee_recipient = Some(decoded_layout.fee_recipient);
+    track_volume = Some(decoded_layout.track_volume);
+    total_fee = creator_fee + protocol_fee;
     token_amount = decoded_layout.token_amount;
     pool_token_a_vault_amount = decoded_layout.real_token_reserves;
     pool_token_b_vault_amount = decoded_layout.real_sol_reserves;
@@ -61,8 +86,9 @@ pub fn process_pumpfun_event_instruction(
       (decoded_layout.fee_basis_points + decoded_layout.creator_fee_basis_points) * 100000;
   }
   // The old creator fee event pre creator fee update
-  else if instruction.data.len() == 137 {
-    let decoded_layout = PfTradeEventIdlOld::try_from_slice(instruction.data).unwrap();
+  else if layout == Some(EventLayout::PfTradeEventOld) {
+    let (decoded_layout, tail) = decode_event_prefix::<PfTradeEventIdlOld>(instruction.data)?;
+    unknown_event_data = tail.to_vec();
     token_address = decoded_layout.mint;
     is_buy = decoded_layout.is_buy;
     sol_amount = decoded_layout.sol_amount;
@@ -80,11 +106,14 @@ pub fn process_pumpfun_event_instruction(
     fee_fraction_lp = 10000000;
     // Fee was 1% of sol amount involved both on the way in added on and on the way out subtracted off
     total_fee = sol_amount / 100;
+    protocol_fee = total_fee;
+    creator_fee = 0;
+    // Old events don't carry creator, fee recipient or volume tracking
+    creator = None;
+    fee_recipient = None;
+    track_volume = None;
   } else {
-    panic!(
-      "Pumpfun: Found a swap event, but data length is not recognized, tx signature: {}",
-      signature
-    );
+    return None;
   }
 
   let market_address = derive_bonding_curve(&token_address);
@@ -102,7 +131,14 @@ pub fn process_pumpfun_event_instruction(
     swapped_amount_received = sol_amount - total_fee;
   }
 
-  SwapTx {
+  let intent = swap_instruction.map(|swap_instruction| {
+    let mut intent = decode_pf_bonding_curve_swap_intent(swap_instruction);
+    intent.slippage_tolerance_bps =
+      compute_slippage_tolerance_bps(&intent, swapped_amount_in, swapped_amount_received);
+    intent
+  });
+
+  let swap_tx = SwapTx {
     pool: Pools::PfBondingCurve,
     direction,
     block_time,
@@ -128,5 +164,29 @@ pub fn process_pumpfun_event_instruction(
     market_address,
     signature: signature.clone(),
     signers: signers.clone(),
-  }
+  };
+  let swap_details = SwapDetails {
+    signature: signature.clone(),
+    atomic_instruction_index,
+    intent,
+    // Filled in by `apply_swap_decimals` once the whole transaction is decoded
+    token_a_decimals: None,
+    token_b_decimals: None,
+    price_a_b_ui: None,
+    price_b_a_ui: None,
+    // Bonding curve fees are charged in SOL and there is no lp fee
+    fees: Some(SwapFees {
+      lp_fee: 0,
+      protocol_fee,
+      creator_fee,
+      platform_fee: 0,
+      referral_fee: 0,
+      fee_token: TOKENS.wsol,
+    }),
+    creator,
+    fee_recipient,
+    track_volume,
+    unknown_event_data,
+  };
+  Some((swap_tx, swap_details))
 }
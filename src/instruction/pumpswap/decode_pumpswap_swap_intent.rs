@@ -0,0 +1,27 @@
+use crate::types::swap_intent::SwapIntent;
+use crate::types::swap_mode::SwapMode;
+use solana_central::Instruction;
+use solana_central::constants::PUMP_CONSTANTS;
+
+/// Decode the swap arguments of a Pumpswap swap instruction. Assumes the instruction has been
+/// validated as a valid Pumpswap swap.
+pub fn decode_pumpswap_swap_intent(instruction: &Instruction) -> SwapIntent {
+  let first_arg = u64::from_le_bytes(instruction.data[8..16].try_into().unwrap());
+  let second_arg = u64::from_le_bytes(instruction.data[16..24].try_into().unwrap());
+  /*
+  buy: base_amount_out, max_quote_amount_in
+  sell: base_amount_in, min_quote_amount_out
+  buy_exact_quote_in: spendable_quote_in, min_base_amount_out
+  */
+  let mode = if instruction.data[0..8] == PUMP_CONSTANTS.buy_instruction_discriminator {
+    SwapMode::ExactOut
+  } else {
+    SwapMode::ExactIn
+  };
+  SwapIntent {
+    mode,
+    specified_amount: first_arg,
+    limit_amount: second_arg,
+    slippage_tolerance_bps: None,
+  }
+}
@@ -1,2 +1,3 @@
+pub mod decode_pumpswap_swap_intent;
 pub mod process_pumpswap_swap_instruction;
 pub mod is_pumpswap_swap_instruction;
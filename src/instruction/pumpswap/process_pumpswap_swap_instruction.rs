@@ -1,8 +1,16 @@
+use crate::constants::event_layouts::EVENT_LAYOUTS;
+use crate::instruction::pumpswap::decode_pumpswap_swap_intent::decode_pumpswap_swap_intent;
+use crate::instruction::resolve_event_layout::resolve_event_layout;
+use crate::types::event_layout::EventLayout;
+use crate::types::swap_details::SwapDetails;
+use crate::types::swap_fees::SwapFees;
+use crate::utilities::compute_slippage_tolerance_bps::compute_slippage_tolerance_bps;
+use crate::utilities::decode_event_prefix::decode_event_prefix;
 use solana_central::protocol_idls::pumpswap::{
   PumpswapBuyEventIdl, PumpswapSellEventIdl,
 };
-use borsh::BorshDeserialize;
 use solana_central::constants::LAMPORTS_PER_SOL;
+use solana_central::constants::PUMP_CONSTANTS;
 use solana_central::Instruction;
 use solana_central::Pools;
 use solana_central::SwapDirection;
@@ -11,9 +19,11 @@ use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use std::collections::HashSet;
 
-/// Process a Pumpswap swap instruction and create a SwapTx. Assumes the instruction has been
-/// validated as a valid Pumpswap swap. Needs the event instruction that follows the swap
-/// instruction to extract swap details.
+/// Process a Pumpswap swap instruction and create a SwapTx with its SwapDetails. Assumes the
+/// instruction has been validated as a valid Pumpswap swap. Needs the event instruction that
+/// follows the swap instruction to extract swap details. Returns `None` if the event layout is not
+/// in the layout registry, the event can't be decoded with it, its reserves don't cover the swap or
+/// leave an empty vault, or the swap accounts are missing.
 pub fn process_pumpswap_swap_instruction(
   instruction: &Instruction,
   swap_event_instruction: &Instruction,
@@ -24,89 +34,115 @@ pub fn process_pumpswap_swap_instruction(
   atomic_instruction_index: u8,
   signers: &HashSet<Pubkey>,
   signature: &Signature,
-) -> SwapTx {
-  let token_a_address = instruction.tx_account_keys[instruction.accounts[3] as usize];
-  let token_b_address = instruction.tx_account_keys[instruction.accounts[4] as usize];
-  let market_address = instruction.tx_account_keys[instruction.accounts[0] as usize];
+) -> Option<(SwapTx, SwapDetails)> {
+  let account = |i: usize| {
+    let account_index = *instruction.accounts.get(i)?;
+    instruction
+      .tx_account_keys
+      .get(account_index as usize)
+      .copied()
+  };
+  let token_a_address = account(3)?;
+  let token_b_address = account(4)?;
+  let market_address = account(0)?;
 
   let swapped_amount_in;
   let swapped_amount_received;
   let pool_token_a_vault_amount;
   let pool_token_b_vault_amount;
   let fee_fraction_lp;
+  let lp_fee;
+  let protocol_fee;
+  let creator_fee;
+  let creator;
+  let fee_recipient;
 
-  let event_len = swap_event_instruction.data.len();
+  let unknown_event_data;
+
+  // Layouts are resolved from the event discriminator and length, known fields are decoded as a
+  // prefix and anything after them (ix name strings, fields added by upgrades) is kept
+  let layout = resolve_event_layout(
+    &EVENT_LAYOUTS,
+    &PUMP_CONSTANTS.pump_swap_program,
+    swap_event_instruction.data,
+    slot,
+  );
 
   // Sell instruction by pumpswap
-  if direction == SwapDirection::AToB {
-    let decoded_event = PumpswapSellEventIdl::try_from_slice(&swap_event_instruction.data).unwrap();
+  if layout == Some(EventLayout::PumpswapSellEvent) {
+    let (decoded_event, tail) =
+      decode_event_prefix::<PumpswapSellEventIdl>(swap_event_instruction.data)?;
+    unknown_event_data = tail.to_vec();
 
     swapped_amount_in = decoded_event.base_amount_in;
     // user quote amount out is the amount of quote token the user actually received
     swapped_amount_received = decoded_event.user_quote_amount_out;
     // Token vault balances in the event are confirmed to be pre balances
-    pool_token_a_vault_amount = decoded_event.pool_base_token_reserves + swapped_amount_in;
+    pool_token_a_vault_amount = decoded_event
+      .pool_base_token_reserves
+      .checked_add(swapped_amount_in)?;
     // Out of the token vault comes out the user owed, protocol fee, and creator fee
-    pool_token_b_vault_amount = decoded_event.pool_quote_token_reserves
-      - swapped_amount_received
-      - decoded_event.protocol_fee
-      - decoded_event.coin_creator_fee;
-    let total_fee =
-      decoded_event.lp_fee + decoded_event.protocol_fee + decoded_event.coin_creator_fee;
+    pool_token_b_vault_amount = decoded_event
+      .pool_quote_token_reserves
+      .checked_sub(swapped_amount_received)?
+      .checked_sub(decoded_event.protocol_fee)?
+      .checked_sub(decoded_event.coin_creator_fee)?;
+    lp_fee = decoded_event.lp_fee;
+    protocol_fee = decoded_event.protocol_fee;
+    creator_fee = decoded_event.coin_creator_fee;
+    creator = decoded_event.coin_creator;
+    fee_recipient = decoded_event.protocol_fee_recipient;
+    let total_fee = lp_fee as u128 + protocol_fee as u128 + creator_fee as u128;
     // amount out is only what user receives out
     // Divison by zero check for zero swapped amounts
-    fee_fraction_lp = if swapped_amount_received + total_fee == 0 {
-      0
-    } else {
-      (total_fee as u128 * LAMPORTS_PER_SOL / (swapped_amount_received + total_fee) as u128) as u64
-    };
+    fee_fraction_lp = (total_fee * LAMPORTS_PER_SOL)
+      .checked_div(swapped_amount_received as u128 + total_fee)
+      .unwrap_or(0) as u64;
   }
-  // Buy instruction by pumpswap - check event length for backward compatibility
-  else {
-
-    if 
-    // buy
-    event_len == 416 || 
-    // old without ix_name
-    event_len == 401 || 
-    // buy_exact_quote_in event length
-    event_len == 431 {
-      let decoded_event =
-        PumpswapBuyEventIdl::try_from_slice(&swap_event_instruction.data[..401]).unwrap();
-      if event_len == 431 {
-        // Buy exact quote in events have quote amount is as the total amount the user swaps in.
-        // Flipped around garbage protocol
-        swapped_amount_in = decoded_event.quote_amount_in;
-      }
-      else {
-        swapped_amount_in = decoded_event.user_quote_amount_in;
-      }
-      swapped_amount_received = decoded_event.base_amount_out;
-      pool_token_b_vault_amount =
-        decoded_event.pool_quote_token_reserves + decoded_event.quote_amount_in_with_lp_fee;
-      pool_token_a_vault_amount = decoded_event.pool_base_token_reserves - swapped_amount_received;
-      let total_fee =
-        decoded_event.lp_fee + decoded_event.protocol_fee + decoded_event.coin_creator_fee;
-      fee_fraction_lp = if swapped_amount_in == 0 {
-        0
-      } else {
-        (total_fee as u128 * LAMPORTS_PER_SOL / swapped_amount_in as u128) as u64
-      };
-    }
-    else {
-      panic!(
-        "Pumpswap: Found a buy event, but data length {} is not recognized (expected 401 or 416), tx signature: {}",
-        event_len, signature
-      );
+  // Buy instruction by pumpswap
+  else if layout == Some(EventLayout::PumpswapBuyEvent) {
+    let (decoded_event, tail) =
+      decode_event_prefix::<PumpswapBuyEventIdl>(swap_event_instruction.data)?;
+    unknown_event_data = tail.to_vec();
+    let discriminator = instruction.data.get(0..8)?;
+    if discriminator == PUMP_CONSTANTS.pumpswap_buy_exact_quote_in_instruction_discriminator {
+      // Buy exact quote in events have quote amount is as the total amount the user swaps in.
+      // Flipped around garbage protocol
+      swapped_amount_in = decoded_event.quote_amount_in;
+    } else {
+      swapped_amount_in = decoded_event.user_quote_amount_in;
     }
+    swapped_amount_received = decoded_event.base_amount_out;
+    pool_token_b_vault_amount = decoded_event
+      .pool_quote_token_reserves
+      .checked_add(decoded_event.quote_amount_in_with_lp_fee)?;
+    pool_token_a_vault_amount = decoded_event
+      .pool_base_token_reserves
+      .checked_sub(swapped_amount_received)?;
+    lp_fee = decoded_event.lp_fee;
+    protocol_fee = decoded_event.protocol_fee;
+    creator_fee = decoded_event.coin_creator_fee;
+    creator = decoded_event.coin_creator;
+    fee_recipient = decoded_event.protocol_fee_recipient;
+    let total_fee = lp_fee as u128 + protocol_fee as u128 + creator_fee as u128;
+    fee_fraction_lp = (total_fee * LAMPORTS_PER_SOL)
+      .checked_div(swapped_amount_in as u128)
+      .unwrap_or(0) as u64;
+  } else {
+    return None;
   }
 
-  let price_a_b_lp =
-    pool_token_a_vault_amount as u128 * LAMPORTS_PER_SOL / pool_token_b_vault_amount as u128;
-  let price_b_a_lp =
-    pool_token_b_vault_amount as u128 * LAMPORTS_PER_SOL / pool_token_a_vault_amount as u128;
+  // An emptied vault has no price
+  let price_a_b_lp = (pool_token_a_vault_amount as u128 * LAMPORTS_PER_SOL)
+    .checked_div(pool_token_b_vault_amount as u128)?;
+  let price_b_a_lp = (pool_token_b_vault_amount as u128 * LAMPORTS_PER_SOL)
+    .checked_div(pool_token_a_vault_amount as u128)?;
+
+  let mut intent = decode_pumpswap_swap_intent(instruction);
+  intent.slippage_tolerance_bps =
+    compute_slippage_tolerance_bps(&intent, swapped_amount_in, swapped_amount_received);
 
-  SwapTx {
+  let swap_tx = SwapTx {
     pool: Pools::PumpswapAmm,
     direction,
     block_time,
@@ -125,5 +161,30 @@ pub fn process_pumpswap_swap_instruction(
     market_address,
     signature: signature.clone(),
     signers: signers.clone(),
-  }
+  };
+  let swap_details = SwapDetails {
+    signature: signature.clone(),
+    atomic_instruction_index,
+    intent: Some(intent),
+    // Filled in by `apply_swap_decimals` once the whole transaction is decoded
+    token_a_decimals: None,
+    token_b_decimals: None,
+    price_a_b_ui: None,
+    price_b_a_ui: None,
+    // Pumpswap fees are all charged in the quote token
+    fees: Some(SwapFees {
+      lp_fee,
+      protocol_fee,
+      creator_fee,
+      platform_fee: 0,
+      referral_fee: 0,
+      fee_token: token_b_address,
+    }),
+    // Pools without a coin creator have the default pubkey
+    creator: (creator != Pubkey::default()).then_some(creator),
+    fee_recipient: Some(fee_recipient),
+    track_volume: None,
+    unknown_event_data,
+  };
+  Some((swap_tx, swap_details))
 }
@@ -0,0 +1,30 @@
+use crate::constants::discriminators::RAYDIUM_AMMV4_SWAP_BASE_IN_DISCRIMINATOR;
+use crate::types::swap_intent::SwapIntent;
+use crate::types::swap_mode::SwapMode;
+use solana_central::Instruction;
+
+/// Decode the swap arguments of a Raydium AMMv4 swap instruction. Assumes the instruction has been
+/// validated as a valid Raydium AMMv4 swap.
+pub fn decode_raydium_ammv4_swap_intent(instruction: &Instruction) -> SwapIntent {
+  /*
+  swap_base_in: amount_in, minimum_amount_out
+  swap_base_out: max_amount_in, amount_out
+  */
+  let first_arg = u64::from_le_bytes(instruction.data[1..9].try_into().unwrap());
+  let second_arg = u64::from_le_bytes(instruction.data[9..17].try_into().unwrap());
+  if instruction.data[0] == RAYDIUM_AMMV4_SWAP_BASE_IN_DISCRIMINATOR {
+    SwapIntent {
+      mode: SwapMode::ExactIn,
+      specified_amount: first_arg,
+      limit_amount: second_arg,
+      slippage_tolerance_bps: None,
+    }
+  } else {
+    SwapIntent {
+      mode: SwapMode::ExactOut,
+      specified_amount: second_arg,
+      limit_amount: first_arg,
+      slippage_tolerance_bps: None,
+    }
+  }
+}
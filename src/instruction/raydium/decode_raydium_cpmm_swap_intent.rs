@@ -0,0 +1,30 @@
+use crate::constants::discriminators::RAYDIUM_CPMM_SWAP_BASE_INPUT_DISCRIMINATOR;
+use crate::types::swap_intent::SwapIntent;
+use crate::types::swap_mode::SwapMode;
+use solana_central::Instruction;
+
+/// Decode the swap arguments of a Raydium Cpmm swap instruction. Assumes the instruction has been
+/// validated as a valid Raydium Cpmm swap.
+pub fn decode_raydium_cpmm_swap_intent(instruction: &Instruction) -> SwapIntent {
+  /*
+  swap_base_input: amount_in, minimum_amount_out
+  swap_base_output: max_amount_in, amount_out
+  */
+  let first_arg = u64::from_le_bytes(instruction.data[8..16].try_into().unwrap());
+  let second_arg = u64::from_le_bytes(instruction.data[16..24].try_into().unwrap());
+  if instruction.data[0..8] == RAYDIUM_CPMM_SWAP_BASE_INPUT_DISCRIMINATOR {
+    SwapIntent {
+      mode: SwapMode::ExactIn,
+      specified_amount: first_arg,
+      limit_amount: second_arg,
+      slippage_tolerance_bps: None,
+    }
+  } else {
+    SwapIntent {
+      mode: SwapMode::ExactOut,
+      specified_amount: second_arg,
+      limit_amount: first_arg,
+      slippage_tolerance_bps: None,
+    }
+  }
+}
@@ -0,0 +1,28 @@
+use crate::constants::discriminators::RAYDIUM_LAUNCHPAD_BUY_EXACT_IN_DISCRIMINATOR;
+use crate::constants::discriminators::RAYDIUM_LAUNCHPAD_SELL_EXACT_IN_DISCRIMINATOR;
+use crate::types::swap_intent::SwapIntent;
+use crate::types::swap_mode::SwapMode;
+use solana_central::Instruction;
+
+/// Decode the swap arguments of a Raydium launchpad swap instruction. Assumes the instruction has
+/// been validated as a valid Raydium launchpad swap.
+pub fn decode_raydium_launchpad_swap_intent(instruction: &Instruction) -> SwapIntent {
+  /*
+  buy_exact_in/sell_exact_in: amount_in, minimum_amount_out, share_fee_rate
+  buy_exact_out/sell_exact_out: amount_out, maximum_amount_in, share_fee_rate
+  */
+  let discriminator = &instruction.data[0..8];
+  let mode = if discriminator == RAYDIUM_LAUNCHPAD_BUY_EXACT_IN_DISCRIMINATOR
+    || discriminator == RAYDIUM_LAUNCHPAD_SELL_EXACT_IN_DISCRIMINATOR
+  {
+    SwapMode::ExactIn
+  } else {
+    SwapMode::ExactOut
+  };
+  SwapIntent {
+    mode,
+    specified_amount: u64::from_le_bytes(instruction.data[8..16].try_into().unwrap()),
+    limit_amount: u64::from_le_bytes(instruction.data[16..24].try_into().unwrap()),
+    slippage_tolerance_bps: None,
+  }
+}
@@ -1,3 +1,6 @@
+pub mod decode_raydium_ammv4_swap_intent;
+pub mod decode_raydium_cpmm_swap_intent;
+pub mod decode_raydium_launchpad_swap_intent;
 pub mod is_raydium_ammv4_swap_instruction;
 pub mod is_raydium_cpmm_swap_instruction;
 pub mod is_raydium_launchpad_swap_instruction;
@@ -1,3 +1,8 @@
+use crate::instruction::raydium::decode_raydium_ammv4_swap_intent::decode_raydium_ammv4_swap_intent;
+use crate::instruction::token::decode_token_transfer::decode_token_transfer;
+use crate::types::running_token_balances::RunningTokenBalances;
+use crate::types::swap_details::SwapDetails;
+use crate::utilities::compute_slippage_tolerance_bps::compute_slippage_tolerance_bps;
 use solana_central::Instruction;
 use solana_central::Pools;
 use solana_central::SwapDirection;
@@ -8,9 +13,11 @@ use solana_sdk::signature::Signature;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-/// Process a Raydium AMMv4 swap instruction and create a SwapTx. Assumes the instruction has been
-/// validated as a valid Raydium AMMv4 swap. Uses token transfer instructions that follow the swap
-/// to determine swap amounts.
+/// Process a Raydium AMMv4 swap instruction and create a SwapTx with its SwapDetails. Assumes the
+/// instruction has been validated as a valid Raydium AMMv4 swap. Uses token transfer instructions
+/// that follow the swap to determine swap amounts, net of Token-2022 transfer fees. Returns `None`
+/// if the swap has an account layout that is not recognized, or its transfers, vault mints or vault
+/// balances are missing.
 pub fn process_raydium_ammv4_swap_instruction(
   // The swap instruction itself
   instruction: &Instruction,
@@ -18,15 +25,17 @@ pub fn process_raydium_ammv4_swap_instruction(
   // TODO this might have to be changed depending on whether rpc data returns parsed for this
   transfers: &[Instruction],
   ta_mint: &HashMap<u8, Pubkey>,
-  running_token_balances: &mut HashMap<u8, u64>,
+  running_token_balances: &mut RunningTokenBalances,
   block_time: u64,
   slot: u64,
   index: u64,
   atomic_instruction_index: u8,
   signers: &HashSet<Pubkey>,
   signature: &Signature,
-) -> SwapTx {
-  let market_address = instruction.tx_account_keys[instruction.accounts[1] as usize];
+) -> Option<(SwapTx, SwapDetails)> {
+  let market_address = *instruction
+    .tx_account_keys
+    .get(*instruction.accounts.get(1)? as usize)?;
 
   // account indices change based on length of accounts array
   let token_a_vault_address;
@@ -38,56 +47,68 @@ pub fn process_raydium_ammv4_swap_instruction(
     token_a_vault_address = instruction.accounts[5];
     token_b_vault_address = instruction.accounts[6];
   } else {
-    panic!(
-      "process_raydium_ammv4_swap_instruction: Invalid number of accounts in swap instruction, signature: {}",
-      signature
-    );
+    return None;
   }
   // Identify token addresses involved in tx, not included in swap instruction
-  let token_a_address = *ta_mint.get(&token_a_vault_address).expect(format!("process_raydium_ammv4_swap_instruction: Token a vault address not found in ta_mint, signature: {}", signature).as_str());
-  let token_b_address = *ta_mint.get(&token_b_vault_address).expect(format!("process_raydium_ammv4_swap_instruction: Token b vault address not found in ta_mint, signature: {}", signature).as_str());
+  let token_a_address = *ta_mint.get(&token_a_vault_address)?;
+  let token_b_address = *ta_mint.get(&token_b_vault_address)?;
 
   /*
   Amount in is how much you sent to the pool in the first transfer instruction. Amount out is how
   much you got out of the pool in the second transfer instruction. The amount value is stored in
-  the instruction data following the instruction 1 byte discriminator and is in a u64 value.
-  Direction doesn't influence where amount in and amount out are found and the order of the
-  transfers is always the same. First in then out
+  the transfer instruction data. Direction doesn't influence where amount in and amount out are
+  found and the order of the transfers is always the same. First in then out
   */
-  let swapped_amount_in = u64::from_le_bytes(transfers[0].data[1..9].try_into().unwrap());
-  let swapped_amount_received = u64::from_le_bytes(transfers[1].data[1..9].try_into().unwrap());
+  let transfer_in = decode_token_transfer(transfers.first()?)?;
+  let transfer_out = decode_token_transfer(transfers.get(1)?)?;
+  // What the pool actually received, less than the transfer amount if the mint charges a fee
+  let swapped_amount_in = running_token_balances.received_amount(&transfer_in);
+  // What the user actually received, after a fee withheld by the output mint
+  let swapped_amount_received = running_token_balances.received_amount(&transfer_out);
 
   /*
   Direction here is determined by if you are sending to the token a vault, then its A to B,
   otherwise its B to A the same way that historical ingestion does it
   */
   let direction: SwapDirection;
-  if transfers[0].accounts[1] == token_a_vault_address {
+  if transfer_in.destination == token_a_vault_address {
     direction = SwapDirection::AToB;
-    if let Some(running_token_balance) = running_token_balances.get_mut(&token_a_vault_address) {
-      *running_token_balance += swapped_amount_in;
-    }
-    if let Some(running_token_balance) = running_token_balances.get_mut(&token_b_vault_address) {
-      *running_token_balance -= swapped_amount_received;
-    }
+    running_token_balances.credit(
+      token_a_vault_address,
+      swapped_amount_in,
+      atomic_instruction_index,
+    );
+    running_token_balances.debit(
+      token_b_vault_address,
+      transfer_out.amount,
+      atomic_instruction_index,
+    );
   } else {
     direction = SwapDirection::BToA;
-    if let Some(running_token_balance) = running_token_balances.get_mut(&token_b_vault_address) {
-      *running_token_balance += swapped_amount_in;
-    }
-    if let Some(running_token_balance) = running_token_balances.get_mut(&token_a_vault_address) {
-      *running_token_balance -= swapped_amount_received;
-    }
+    running_token_balances.credit(
+      token_b_vault_address,
+      swapped_amount_in,
+      atomic_instruction_index,
+    );
+    running_token_balances.debit(
+      token_a_vault_address,
+      transfer_out.amount,
+      atomic_instruction_index,
+    );
   };
 
-  let pool_token_a_vault_amount: u64 = running_token_balances[&token_a_vault_address];
-  let pool_token_b_vault_amount: u64 = running_token_balances[&token_b_vault_address];
+  let pool_token_a_vault_amount: u64 = running_token_balances.get(token_a_vault_address)?;
+  let pool_token_b_vault_amount: u64 = running_token_balances.get(token_b_vault_address)?;
   let price_b_a_lp =
     LAMPORTS_PER_SOL * pool_token_b_vault_amount as u128 / pool_token_a_vault_amount as u128;
   let price_a_b_lp =
     LAMPORTS_PER_SOL * pool_token_a_vault_amount as u128 / pool_token_b_vault_amount as u128;
 
-  SwapTx {
+  let mut intent = decode_raydium_ammv4_swap_intent(instruction);
+  intent.slippage_tolerance_bps =
+    compute_slippage_tolerance_bps(&intent, swapped_amount_in, swapped_amount_received);
+
+  let swap_tx = SwapTx {
     pool: Pools::RaydiumAmmV4,
     direction,
     block_time,
@@ -107,5 +128,21 @@ pub fn process_raydium_ammv4_swap_instruction(
     market_address,
     signature: signature.clone(),
     signers: signers.clone(),
-  }
+  };
+  let swap_details = SwapDetails {
+    signature: signature.clone(),
+    atomic_instruction_index,
+    intent: Some(intent),
+    // Filled in by `apply_swap_decimals` once the whole transaction is decoded
+    token_a_decimals: None,
+    token_b_decimals: None,
+    price_a_b_ui: None,
+    price_b_a_ui: None,
+    fees: None,
+    creator: None,
+    fee_recipient: None,
+    track_volume: None,
+    unknown_event_data: Vec::new(),
+  };
+  Some((swap_tx, swap_details))
 }
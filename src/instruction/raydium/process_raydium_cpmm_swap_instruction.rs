@@ -1,3 +1,8 @@
+use crate::instruction::raydium::decode_raydium_cpmm_swap_intent::decode_raydium_cpmm_swap_intent;
+use crate::instruction::token::decode_token_transfer::decode_token_transfer;
+use crate::types::running_token_balances::RunningTokenBalances;
+use crate::types::swap_details::SwapDetails;
+use crate::utilities::compute_slippage_tolerance_bps::compute_slippage_tolerance_bps;
 use solana_central::Instruction;
 use solana_central::Pools;
 use solana_central::SwapDirection;
@@ -6,42 +11,60 @@ use solana_central::constants::LAMPORTS_PER_SOL;
 use solana_central::get_cpmm_fee_amount_from_config_account;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
-use std::collections::HashMap;
 use std::collections::HashSet;
 
-/// Process a Raydium Cpmm swap instruction and create a SwapTx. Assumes the instruction has been
-/// validated as a valid Raydium Cpmm swap. Uses token transfer instructions that follow the swap
-/// to determine swap amounts.
+/// Process a Raydium Cpmm swap instruction and create a SwapTx with its SwapDetails. Assumes the
+/// instruction has been validated as a valid Raydium Cpmm swap. Uses token transfer instructions
+/// that follow the swap to determine swap amounts, net of Token-2022 transfer fees. Returns `None`
+/// if the swap accounts, its transfers or the vault balances are missing.
 pub fn process_raydium_cpmm_swap_instruction(
   // The swap instruction itself
   instruction: &Instruction,
   // The 2 token transfers (into one vault, out of the other vault) that come after the swap
   transfers: &[Instruction],
-  running_token_balances: &mut HashMap<u8, u64>,
+  running_token_balances: &mut RunningTokenBalances,
   block_time: u64,
   slot: u64,
   index: u64,
   atomic_instruction_index: u8,
   signers: &HashSet<Pubkey>,
   signature: &Signature,
-) -> SwapTx {
-  let market_address = instruction.tx_account_keys[instruction.accounts[3] as usize];
-  let input_token_mint = instruction.tx_account_keys[instruction.accounts[10] as usize];
-  let output_token_mint = instruction.tx_account_keys[instruction.accounts[11] as usize];
-  let input_token_vault = instruction.accounts[6];
-  let output_token_vault = instruction.accounts[7];
+) -> Option<(SwapTx, SwapDetails)> {
+  let account = |i: usize| {
+    let account_index = *instruction.accounts.get(i)?;
+    instruction
+      .tx_account_keys
+      .get(account_index as usize)
+      .copied()
+  };
+  let market_address = account(3)?;
+  let input_token_mint = account(10)?;
+  let output_token_mint = account(11)?;
+  // The fee config is the third account
+  let fee_config = account(2)?;
+  let input_token_vault = *instruction.accounts.get(6)?;
+  let output_token_vault = *instruction.accounts.get(7)?;
 
-  // Pool's input token balance will go up as the user sends funds to the pool
-  let swapped_amount_in = u64::from_le_bytes(transfers[0].data[1..9].try_into().unwrap());
-  if let Some(running_token_balance) = running_token_balances.get_mut(&input_token_vault) {
-    *running_token_balance += swapped_amount_in;
-  }
+  let transfer_in = decode_token_transfer(transfers.first()?)?;
+  let transfer_out = decode_token_transfer(transfers.get(1)?)?;
 
-  // Pool's output token balance will go down as the pool sends funds to the user
-  let swapped_amount_received = u64::from_le_bytes(transfers[1].data[1..9].try_into().unwrap());
-  if let Some(running_token_balance) = running_token_balances.get_mut(&output_token_vault) {
-    *running_token_balance -= swapped_amount_received;
-  }
+  // Pool's input token balance will go up as the user sends funds to the pool, by less than the
+  // transfer amount if the input mint charges a transfer fee
+  let swapped_amount_in = running_token_balances.received_amount(&transfer_in);
+  running_token_balances.credit(
+    input_token_vault,
+    swapped_amount_in,
+    atomic_instruction_index,
+  );
+
+  // Pool's output token balance will go down as the pool sends funds to the user, who receives
+  // less than the transfer amount if the output mint charges a transfer fee
+  running_token_balances.debit(
+    output_token_vault,
+    transfer_out.amount,
+    atomic_instruction_index,
+  );
+  let swapped_amount_received = running_token_balances.received_amount(&transfer_out);
 
   let direction;
   let token_a_address;
@@ -67,21 +90,20 @@ pub fn process_raydium_cpmm_swap_instruction(
     direction = SwapDirection::BToA;
   }
 
-  // The fee config is the third account
-
-  let fee_fraction_lp = get_cpmm_fee_amount_from_config_account(
-    instruction.tx_account_keys[instruction.accounts[2] as usize],
-    &market_address,
-  );
+  let fee_fraction_lp = get_cpmm_fee_amount_from_config_account(fee_config, &market_address);
 
-  let pool_token_a_vault_amount = running_token_balances[&token_a_vault_address];
-  let pool_token_b_vault_amount = running_token_balances[&token_b_vault_address];
+  let pool_token_a_vault_amount = running_token_balances.get(token_a_vault_address)?;
+  let pool_token_b_vault_amount = running_token_balances.get(token_b_vault_address)?;
   let price_b_a_lp =
     LAMPORTS_PER_SOL * pool_token_b_vault_amount as u128 / pool_token_a_vault_amount as u128;
   let price_a_b_lp =
     LAMPORTS_PER_SOL * pool_token_a_vault_amount as u128 / pool_token_b_vault_amount as u128;
 
-  SwapTx {
+  let mut intent = decode_raydium_cpmm_swap_intent(instruction);
+  intent.slippage_tolerance_bps =
+    compute_slippage_tolerance_bps(&intent, swapped_amount_in, swapped_amount_received);
+
+  let swap_tx = SwapTx {
     pool: Pools::RaydiumCpmm,
     direction,
     block_time,
@@ -101,5 +123,21 @@ pub fn process_raydium_cpmm_swap_instruction(
     market_address,
     signature: signature.clone(),
     signers: signers.clone(),
-  }
+  };
+  let swap_details = SwapDetails {
+    signature: signature.clone(),
+    atomic_instruction_index,
+    intent: Some(intent),
+    // Filled in by `apply_swap_decimals` once the whole transaction is decoded
+    token_a_decimals: None,
+    token_b_decimals: None,
+    price_a_b_ui: None,
+    price_b_a_ui: None,
+    fees: None,
+    creator: None,
+    fee_recipient: None,
+    track_volume: None,
+    unknown_event_data: Vec::new(),
+  };
+  Some((swap_tx, swap_details))
 }
@@ -1,3 +1,7 @@
+use crate::instruction::raydium::decode_raydium_launchpad_swap_intent::decode_raydium_launchpad_swap_intent;
+use crate::types::swap_details::SwapDetails;
+use crate::types::swap_fees::SwapFees;
+use crate::utilities::compute_slippage_tolerance_bps::compute_slippage_tolerance_bps;
 use borsh::BorshDeserialize;
 use solana_central::Instruction;
 use solana_central::Pools;
@@ -9,10 +13,11 @@ use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use std::collections::HashSet;
 
-/// Process a Raydium launchpad swap instruction and create a SwapTx. Assumes the instruction has
-/// been validated as a valid Raydium launchpad swap. Requires both the swap instruction and its
-/// corresponding event instruction because the platform config cannot be derived from the event
-/// data alone.
+/// Process a Raydium launchpad swap instruction and create a SwapTx with its SwapDetails. Assumes
+/// the instruction has been validated as a valid Raydium launchpad swap. Requires both the swap
+/// instruction and its corresponding event instruction because the platform config cannot be
+/// derived from the event data alone. Returns `None` if the swap accounts are missing, the event
+/// can't be decoded or its reserves leave an empty curve.
 pub fn process_raydium_launchpad_swap_instruction(
   instruction: &Instruction,
   event: &Instruction,
@@ -23,37 +28,56 @@ pub fn process_raydium_launchpad_swap_instruction(
   atomic_instruction_index: u8,
   signers: &HashSet<Pubkey>,
   signature: &Signature,
-) -> SwapTx {
-  let token_a_address = instruction.tx_account_keys[instruction.accounts[9] as usize];
-  let token_b_address = instruction.tx_account_keys[instruction.accounts[10] as usize];
-  let market_address = instruction.tx_account_keys[instruction.accounts[4] as usize];
-  let swap_event = LaunchpadTradeEventIdl::try_from_slice(&event.data).unwrap();
+) -> Option<(SwapTx, SwapDetails)> {
+  let account = |i: usize| {
+    let account_index = *instruction.accounts.get(i)?;
+    instruction
+      .tx_account_keys
+      .get(account_index as usize)
+      .copied()
+  };
+  let token_a_address = account(9)?;
+  let token_b_address = account(10)?;
+  let market_address = account(4)?;
+  let swap_event = LaunchpadTradeEventIdl::try_from_slice(&event.data).ok()?;
 
   let swapped_amount_in = swap_event.amount_in;
   let swapped_amount_received = swap_event.amount_out;
-  let total_swap_fee = (swap_event.protocol_fee
-    + swap_event.platform_fee
-    + swap_event.share_fee
-    + swap_event.creator_fee) as u128;
+  let total_swap_fee = swap_event.protocol_fee as u128
+    + swap_event.platform_fee as u128
+    + swap_event.share_fee as u128
+    + swap_event.creator_fee as u128;
   let fee_fraction_lp;
 
   // If sell, charged on way out, if buy, charged on way in
   if direction == SwapDirection::AToB {
-    fee_fraction_lp = (total_swap_fee * LAMPORTS_PER_SOL
-      / (swapped_amount_received as u128 + total_swap_fee)) as u64;
+    fee_fraction_lp = (total_swap_fee * LAMPORTS_PER_SOL)
+      .checked_div(swapped_amount_received as u128 + total_swap_fee)
+      .unwrap_or(0) as u64;
   } else {
-    fee_fraction_lp = (total_swap_fee * LAMPORTS_PER_SOL / swapped_amount_in as u128) as u64;
+    fee_fraction_lp = (total_swap_fee * LAMPORTS_PER_SOL)
+      .checked_div(swapped_amount_in as u128)
+      .unwrap_or(0) as u64;
   }
 
-  let pool_token_a_vault_amount = swap_event.virtual_base - swap_event.real_base_after;
-  let pool_token_b_vault_amount = swap_event.virtual_quote + swap_event.real_quote_after;
+  let pool_token_a_vault_amount = swap_event
+    .virtual_base
+    .checked_sub(swap_event.real_base_after)?;
+  let pool_token_b_vault_amount = swap_event
+    .virtual_quote
+    .checked_add(swap_event.real_quote_after)?;
 
-  let price_a_b_lp =
-    pool_token_a_vault_amount as u128 * LAMPORTS_PER_SOL / pool_token_b_vault_amount as u128;
-  let price_b_a_lp =
-    pool_token_b_vault_amount as u128 * LAMPORTS_PER_SOL / pool_token_a_vault_amount as u128;
+  // An emptied curve has no price
+  let price_a_b_lp = (pool_token_a_vault_amount as u128 * LAMPORTS_PER_SOL)
+    .checked_div(pool_token_b_vault_amount as u128)?;
+  let price_b_a_lp = (pool_token_b_vault_amount as u128 * LAMPORTS_PER_SOL)
+    .checked_div(pool_token_a_vault_amount as u128)?;
 
-  SwapTx {
+  let mut intent = decode_raydium_launchpad_swap_intent(instruction);
+  intent.slippage_tolerance_bps =
+    compute_slippage_tolerance_bps(&intent, swapped_amount_in, swapped_amount_received);
+
+  let swap_tx = SwapTx {
     pool: Pools::RaydiumLaunchpad,
     direction,
     block_time,
@@ -72,5 +96,29 @@ pub fn process_raydium_launchpad_swap_instruction(
     market_address,
     signature: signature.clone(),
     signers: signers.clone(),
-  }
+  };
+  let swap_details = SwapDetails {
+    signature: signature.clone(),
+    atomic_instruction_index,
+    intent: Some(intent),
+    // Filled in by `apply_swap_decimals` once the whole transaction is decoded
+    token_a_decimals: None,
+    token_b_decimals: None,
+    price_a_b_ui: None,
+    price_b_a_ui: None,
+    // Launchpad fees are all charged in the quote token and the bonding curve has no lp fee
+    fees: Some(SwapFees {
+      lp_fee: 0,
+      protocol_fee: swap_event.protocol_fee,
+      creator_fee: swap_event.creator_fee,
+      platform_fee: swap_event.platform_fee,
+      referral_fee: swap_event.share_fee,
+      fee_token: token_b_address,
+    }),
+    creator: None,
+    fee_recipient: None,
+    track_volume: None,
+    unknown_event_data: Vec::new(),
+  };
+  Some((swap_tx, swap_details))
 }
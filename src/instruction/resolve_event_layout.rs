@@ -0,0 +1,119 @@
+use crate::types::event_layout::EventLayout;
+use crate::types::event_layout_entry::EventLayoutEntry;
+use solana_sdk::pubkey::Pubkey;
+
+/// Find the layout of an event in a layout registry such as `EVENT_LAYOUTS`. When several entries
+/// match, the one with the largest `min_len` is the most specific and wins. Returns `None` if no
+/// entry matches.
+pub fn resolve_event_layout(
+  layouts: &[EventLayoutEntry],
+  program_id: &Pubkey,
+  data: &[u8],
+  slot: u64,
+) -> Option<EventLayout> {
+  layouts
+    .iter()
+    .filter(|entry| {
+      entry.program_id == *program_id
+        && data.starts_with(&entry.discriminator)
+        && data.len() >= entry.min_len
+        && entry.max_len.is_none_or(|max_len| data.len() < max_len)
+        && entry
+          .slots
+          .as_ref()
+          .is_none_or(|slots| slots.contains(&slot))
+    })
+    .max_by_key(|entry| entry.min_len)
+    .map(|entry| entry.layout)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::constants::event_layouts::EVENT_LAYOUTS;
+  use crate::constants::event_layouts::PF_TRADE_EVENT_DISCRIMINATOR;
+  use crate::constants::event_layouts::PUMPSWAP_BUY_EVENT_DISCRIMINATOR;
+  use crate::constants::event_layouts::PUMPSWAP_SELL_EVENT_DISCRIMINATOR;
+  use solana_central::constants::PUMP_CONSTANTS;
+
+  fn event(discriminator: [u8; 16], len: usize) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    data.resize(len, 0);
+    data
+  }
+
+  #[test]
+  fn pumpfun_trade_event_layout_depends_on_length() {
+    let program_id = PUMP_CONSTANTS.bonding_curve_program;
+    let resolve = |len| {
+      resolve_event_layout(
+        &EVENT_LAYOUTS,
+        &program_id,
+        &event(PF_TRADE_EVENT_DISCRIMINATOR, len),
+        0,
+      )
+    };
+    assert_eq!(resolve(136), None);
+    assert_eq!(resolve(137), Some(EventLayout::PfTradeEventOld));
+    assert_eq!(resolve(265), Some(EventLayout::PfTradeEventOld));
+    for len in [266, 273, 274, 286] {
+      assert_eq!(resolve(len), Some(EventLayout::PfTradeEventCurrent));
+    }
+  }
+
+  #[test]
+  fn pumpswap_event_layouts() {
+    let program_id = PUMP_CONSTANTS.pump_swap_program;
+    let resolve = |discriminator, len| {
+      resolve_event_layout(&EVENT_LAYOUTS, &program_id, &event(discriminator, len), 0)
+    };
+    assert_eq!(resolve(PUMPSWAP_BUY_EVENT_DISCRIMINATOR, 400), None);
+    assert_eq!(
+      resolve(PUMPSWAP_BUY_EVENT_DISCRIMINATOR, 416),
+      Some(EventLayout::PumpswapBuyEvent)
+    );
+    // A truncated sell event must not resolve to a layout it can't be decoded with
+    assert_eq!(resolve(PUMPSWAP_SELL_EVENT_DISCRIMINATOR, 16), None);
+    assert_eq!(resolve(PUMPSWAP_SELL_EVENT_DISCRIMINATOR, 367), None);
+    assert_eq!(
+      resolve(PUMPSWAP_SELL_EVENT_DISCRIMINATOR, 368),
+      Some(EventLayout::PumpswapSellEvent)
+    );
+  }
+
+  #[test]
+  fn program_id_must_match() {
+    let data = event(PF_TRADE_EVENT_DISCRIMINATOR, 266);
+    let program_id = PUMP_CONSTANTS.pump_swap_program;
+    assert_eq!(
+      resolve_event_layout(&EVENT_LAYOUTS, &program_id, &data, 0),
+      None
+    );
+  }
+
+  #[test]
+  fn slot_range_and_most_specific_entry_win() {
+    let program_id = Pubkey::new_unique();
+    let entry = |min_len, slots, layout| EventLayoutEntry {
+      program_id,
+      discriminator: PF_TRADE_EVENT_DISCRIMINATOR,
+      min_len,
+      max_len: None,
+      slots,
+      layout,
+    };
+    let layouts = [
+      entry(100, None, EventLayout::PfTradeEventOld),
+      entry(200, Some(10..=20), EventLayout::PfTradeEventCurrent),
+    ];
+    let data = event(PF_TRADE_EVENT_DISCRIMINATOR, 250);
+    assert_eq!(
+      resolve_event_layout(&layouts, &program_id, &data, 15),
+      Some(EventLayout::PfTradeEventCurrent)
+    );
+    assert_eq!(
+      resolve_event_layout(&layouts, &program_id, &data, 21),
+      Some(EventLayout::PfTradeEventOld)
+    );
+  }
+}
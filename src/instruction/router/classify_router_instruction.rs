@@ -0,0 +1,22 @@
+use crate::constants::router_programs::DFLOW_ROUTER_PROGRAM_ID;
+use crate::constants::router_programs::JUPITER_V6_PROGRAM_ID;
+use crate::constants::router_programs::OKX_ROUTER_PROGRAM_ID;
+use crate::constants::router_programs::TITAN_ROUTER_PROGRAM_ID;
+use crate::types::router::Router;
+use solana_central::Instruction;
+
+/// Which router an instruction calls, `None` if its program is not a supported router
+pub fn classify_router_instruction(instruction: &Instruction) -> Option<Router> {
+  let program_id = instruction.tx_account_keys[instruction.program_id_index as usize];
+  if program_id == JUPITER_V6_PROGRAM_ID {
+    Some(Router::JupiterV6)
+  } else if program_id == OKX_ROUTER_PROGRAM_ID {
+    Some(Router::Okx)
+  } else if program_id == DFLOW_ROUTER_PROGRAM_ID {
+    Some(Router::DFlow)
+  } else if program_id == TITAN_ROUTER_PROGRAM_ID {
+    Some(Router::Titan)
+  } else {
+    None
+  }
+}
@@ -0,0 +1,24 @@
+use solana_sdk::pubkey::Pubkey;
+
+/// Collapse the legs of a route, each `(input mint, input amount, output mint, output amount)` in
+/// execution order, into the route's input mint, output mint, input amount and output amount. The
+/// input mint is what the first leg spends and the output mint what the last leg receives, amounts
+/// are summed over all legs spending the input mint and receiving the output mint so split routes
+/// are counted fully. `None` if there are no legs.
+pub fn collapse_route_legs(
+  legs: &[(Pubkey, u64, Pubkey, u64)],
+) -> Option<(Pubkey, Pubkey, u64, u64)> {
+  let input_mint = legs.first()?.0;
+  let output_mint = legs.last()?.2;
+  let mut amount_in: u64 = 0;
+  let mut amount_out: u64 = 0;
+  for (leg_input_mint, leg_amount_in, leg_output_mint, leg_amount_out) in legs {
+    if *leg_input_mint == input_mint {
+      amount_in = amount_in.saturating_add(*leg_amount_in);
+    }
+    if *leg_output_mint == output_mint {
+      amount_out = amount_out.saturating_add(*leg_amount_out);
+    }
+  }
+  Some((input_mint, output_mint, amount_in, amount_out))
+}
@@ -0,0 +1,48 @@
+use crate::instruction::router::collapse_route_legs::collapse_route_legs;
+use crate::instruction::router::swap_route_legs::swap_route_legs;
+use crate::types::route_trade::RouteTrade;
+use crate::types::router::Router;
+use solana_central::SwapTx;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashSet;
+
+/// Build a `RouteTrade` for a router whose instructions and events aren't decoded, from the child
+/// swaps decoded inside its top level instruction. Hops on pools this library doesn't decode are
+/// missing, so amounts are only complete if every hop is on a supported pool. `None` if no child
+/// swaps were decoded.
+pub fn collapse_route_swaps(
+  router: Router,
+  child_swaps: &[SwapTx],
+  block_time: u64,
+  slot: u64,
+  index: u64,
+  instruction_index: u8,
+  signers: &HashSet<Pubkey>,
+  signature: &Signature,
+) -> Option<RouteTrade> {
+  let (input_mint, output_mint, amount_in, amount_out) =
+    collapse_route_legs(&swap_route_legs(child_swaps))?;
+  Some(RouteTrade {
+    router,
+    signature: signature.clone(),
+    block_time,
+    slot,
+    index,
+    instruction_index,
+    signers: signers.clone(),
+    input_mint,
+    output_mint,
+    amount_in,
+    amount_out,
+    intent: None,
+    slippage_bps: None,
+    platform_fee_bps: None,
+    platform_fee_amount: 0,
+    platform_fee_mint: None,
+    hops: child_swaps
+      .iter()
+      .map(|swap| swap.atomic_instruction_index)
+      .collect(),
+  })
+}
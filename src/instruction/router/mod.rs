@@ -0,0 +1,6 @@
+pub mod classify_router_instruction;
+pub mod collapse_route_legs;
+pub mod collapse_route_swaps;
+pub mod process_jupiter_route_instruction;
+pub mod process_router_instruction;
+pub mod swap_route_legs;
@@ -0,0 +1,246 @@
+use crate::constants::discriminators::ANCHOR_EVENT_CPI_TAG;
+use crate::constants::discriminators::JUPITER_EXACT_OUT_ROUTE_DISCRIMINATOR;
+use crate::constants::discriminators::JUPITER_FEE_EVENT_DISCRIMINATOR;
+use crate::constants::discriminators::JUPITER_ROUTE_DISCRIMINATOR;
+use crate::constants::discriminators::JUPITER_ROUTE_WITH_TOKEN_LEDGER_DISCRIMINATOR;
+use crate::constants::discriminators::JUPITER_SHARED_ACCOUNTS_EXACT_OUT_ROUTE_DISCRIMINATOR;
+use crate::constants::discriminators::JUPITER_SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR;
+use crate::constants::discriminators::JUPITER_SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER_DISCRIMINATOR;
+use crate::constants::discriminators::JUPITER_SWAP_EVENT_DISCRIMINATOR;
+use crate::constants::router_programs::JUPITER_V6_PROGRAM_ID;
+use crate::instruction::router::collapse_route_legs::collapse_route_legs;
+use crate::instruction::router::swap_route_legs::swap_route_legs;
+use crate::types::route_trade::RouteTrade;
+use crate::types::router::Router;
+use crate::types::swap_intent::SwapIntent;
+use crate::types::swap_mode::SwapMode;
+use crate::utilities::compute_slippage_tolerance_bps::compute_slippage_tolerance_bps;
+use solana_central::Instruction;
+use solana_central::SwapTx;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashSet;
+
+/// Process a Jupiter v6 `route`, `shared_accounts_route`, `exact_out_route`,
+/// `shared_accounts_exact_out_route` or token ledger route top level instruction into a
+/// `RouteTrade`. Amounts come from the `SwapEvent`s Jupiter emits per hop, so hops on pools this
+/// library doesn't decode are counted, and the platform fee from the `FeeEvent`. Falls back to the
+/// child swaps if there are no events. The token ledger routes have no input amount argument, their
+/// intent uses the spent amount. `None` for other Jupiter instructions.
+pub fn process_jupiter_route_instruction(
+  instruction: &Instruction,
+  inner_instructions: &[Instruction],
+  child_swaps: &[SwapTx],
+  block_time: u64,
+  slot: u64,
+  index: u64,
+  instruction_index: u8,
+  signers: &HashSet<Pubkey>,
+  signature: &Signature,
+) -> Option<RouteTrade> {
+  let data = instruction.data;
+  let discriminator = data.get(0..8)?;
+  // Whether the amount the user fixed is an argument, token ledger routes spend the ledger balance
+  let (mode, has_amount_argument) = if discriminator == JUPITER_ROUTE_DISCRIMINATOR
+    || discriminator == JUPITER_SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR
+  {
+    (SwapMode::ExactIn, true)
+  } else if discriminator == JUPITER_EXACT_OUT_ROUTE_DISCRIMINATOR
+    || discriminator == JUPITER_SHARED_ACCOUNTS_EXACT_OUT_ROUTE_DISCRIMINATOR
+  {
+    (SwapMode::ExactOut, true)
+  } else if discriminator == JUPITER_ROUTE_WITH_TOKEN_LEDGER_DISCRIMINATOR
+    || discriminator == JUPITER_SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER_DISCRIMINATOR
+  {
+    (SwapMode::ExactIn, false)
+  } else {
+    return None;
+  };
+  /*
+  ExactIn: in_amount, quoted_out_amount, slippage_bps, platform_fee_bps
+  ExactOut: out_amount, quoted_in_amount, slippage_bps, platform_fee_bps
+  Token ledger: quoted_out_amount, slippage_bps, platform_fee_bps
+  Route plan is variable length, so the fixed arguments are read from the end
+  */
+  let tail_len = if has_amount_argument { 19 } else { 11 };
+  if data.len() < 8 + tail_len {
+    return None;
+  }
+  let tail = &data[data.len() - tail_len..];
+  let (specified_amount, tail) = if has_amount_argument {
+    (
+      Some(u64::from_le_bytes(tail[0..8].try_into().unwrap())),
+      &tail[8..],
+    )
+  } else {
+    (None, tail)
+  };
+  let quoted_amount = u64::from_le_bytes(tail[0..8].try_into().unwrap());
+  let slippage_bps = u16::from_le_bytes(tail[8..10].try_into().unwrap());
+  let platform_fee_bps = tail[10];
+  let limit_amount = match mode {
+    SwapMode::ExactIn => quoted_amount as u128 * (10000 - slippage_bps.min(10000)) as u128 / 10000,
+    SwapMode::ExactOut => quoted_amount as u128 * (10000 + slippage_bps as u128) / 10000,
+  }
+  .min(u64::MAX as u128) as u64;
+
+  let mut legs = Vec::new();
+  let mut platform_fee_amount = 0;
+  let mut platform_fee_mint = None;
+  for inner_instruction in inner_instructions {
+    let event_data = inner_instruction.data;
+    if inner_instruction.tx_account_keys[inner_instruction.program_id_index as usize]
+      != JUPITER_V6_PROGRAM_ID
+      || event_data.len() < 16
+      || event_data[0..8] != ANCHOR_EVENT_CPI_TAG
+    {
+      continue;
+    }
+    let pubkey_at =
+      |offset: usize| Pubkey::new_from_array(event_data[offset..offset + 32].try_into().unwrap());
+    let u64_at =
+      |offset: usize| u64::from_le_bytes(event_data[offset..offset + 8].try_into().unwrap());
+    // SwapEvent: amm, input_mint, input_amount, output_mint, output_amount
+    if event_data[8..16] == JUPITER_SWAP_EVENT_DISCRIMINATOR && event_data.len() >= 128 {
+      legs.push((pubkey_at(48), u64_at(80), pubkey_at(88), u64_at(120)));
+    }
+    // FeeEvent: account, mint, amount
+    else if event_data[8..16] == JUPITER_FEE_EVENT_DISCRIMINATOR && event_data.len() >= 88 {
+      platform_fee_mint = Some(pubkey_at(48));
+      platform_fee_amount += u64_at(80);
+    }
+  }
+
+  let (input_mint, output_mint, amount_in, amount_out) = match collapse_route_legs(&legs) {
+    Some(collapsed) => collapsed,
+    None => collapse_route_legs(&swap_route_legs(child_swaps))?,
+  };
+  let mut intent = SwapIntent {
+    mode,
+    specified_amount: specified_amount.unwrap_or(amount_in),
+    limit_amount,
+    slippage_tolerance_bps: None,
+  };
+  intent.slippage_tolerance_bps = compute_slippage_tolerance_bps(&intent, amount_in, amount_out);
+
+  Some(RouteTrade {
+    router: Router::JupiterV6,
+    signature: signature.clone(),
+    block_time,
+    slot,
+    index,
+    instruction_index,
+    signers: signers.clone(),
+    input_mint,
+    output_mint,
+    amount_in,
+    amount_out,
+    intent: Some(intent),
+    slippage_bps: Some(slippage_bps),
+    platform_fee_bps: Some(platform_fee_bps),
+    platform_fee_amount,
+    platform_fee_mint,
+    hops: child_swaps
+      .iter()
+      .map(|swap| swap.atomic_instruction_index)
+      .collect(),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utilities::test_swap_tx_builder::TestSwapTxBuilder;
+
+  fn decode(data: &[u8], child_swaps: &[SwapTx]) -> Option<RouteTrade> {
+    let account_keys = vec![JUPITER_V6_PROGRAM_ID];
+    let accounts = Vec::new();
+    let instruction = Instruction {
+      tx_account_keys: &account_keys,
+      accounts: &accounts,
+      data,
+      program_id_index: 0,
+    };
+    process_jupiter_route_instruction(
+      &instruction,
+      &[],
+      child_swaps,
+      1,
+      2,
+      3,
+      0,
+      &HashSet::new(),
+      &Signature::new_unique(),
+    )
+  }
+
+  /// Instruction data with an empty route plan followed by the fixed arguments
+  fn route_data(discriminator: [u8; 8], specified_amount: Option<u64>) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    // route_plan length
+    data.extend_from_slice(&0u32.to_le_bytes());
+    if let Some(specified_amount) = specified_amount {
+      data.extend_from_slice(&specified_amount.to_le_bytes());
+    }
+    // quoted_amount, slippage_bps, platform_fee_bps
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&50u16.to_le_bytes());
+    data.push(20);
+    data
+  }
+
+  #[test]
+  fn falls_back_to_child_swaps_without_events() {
+    let (a, b, c) = (
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+    );
+    let child_swaps = vec![
+      TestSwapTxBuilder::default()
+        .tokens(a, b)
+        .amounts(500, 700)
+        .build(),
+      TestSwapTxBuilder::default()
+        .tokens(b, c)
+        .amounts(700, 990)
+        .build(),
+    ];
+    let route_trade = decode(
+      &route_data(JUPITER_ROUTE_DISCRIMINATOR, Some(500)),
+      &child_swaps,
+    )
+    .unwrap();
+    assert_eq!(route_trade.input_mint, a);
+    assert_eq!(route_trade.output_mint, c);
+    assert_eq!(route_trade.amount_in, 500);
+    assert_eq!(route_trade.amount_out, 990);
+    assert_eq!(route_trade.slippage_bps, Some(50));
+    assert_eq!(route_trade.platform_fee_bps, Some(20));
+    assert!(decode(&route_data(JUPITER_ROUTE_DISCRIMINATOR, Some(500)), &[]).is_none());
+  }
+
+  #[test]
+  fn token_ledger_routes_specify_the_spent_amount() {
+    let child_swaps = vec![TestSwapTxBuilder::default().amounts(800, 990).build()];
+    for discriminator in [
+      JUPITER_ROUTE_WITH_TOKEN_LEDGER_DISCRIMINATOR,
+      JUPITER_SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER_DISCRIMINATOR,
+    ] {
+      let route_trade = decode(&route_data(discriminator, None), &child_swaps).unwrap();
+      let intent = route_trade.intent.unwrap();
+      assert_eq!(intent.mode, SwapMode::ExactIn);
+      assert_eq!(intent.specified_amount, 800);
+      assert_eq!(intent.limit_amount, 995);
+      assert_eq!(route_trade.slippage_bps, Some(50));
+      assert_eq!(route_trade.platform_fee_bps, Some(20));
+    }
+  }
+
+  #[test]
+  fn other_instructions_are_none() {
+    let child_swaps = vec![TestSwapTxBuilder::default().amounts(800, 990).build()];
+    assert!(decode(&route_data([0; 8], Some(800)), &child_swaps).is_none());
+    assert!(decode(&JUPITER_ROUTE_DISCRIMINATOR[..4], &child_swaps).is_none());
+  }
+}
@@ -0,0 +1,49 @@
+use crate::instruction::router::collapse_route_swaps::collapse_route_swaps;
+use crate::instruction::router::process_jupiter_route_instruction::process_jupiter_route_instruction;
+use crate::types::route_trade::RouteTrade;
+use crate::types::router::Router;
+use solana_central::Instruction;
+use solana_central::SwapTx;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashSet;
+
+/// Process a top level router instruction into a `RouteTrade`. `inner_instructions` are the inner
+/// instructions it yielded and `child_swaps` the swaps decoded from them. Jupiter routes are
+/// decoded from their arguments and events, other routers are collapsed from their child swaps.
+pub fn process_router_instruction(
+  router: Router,
+  instruction: &Instruction,
+  inner_instructions: &[Instruction],
+  child_swaps: &[SwapTx],
+  block_time: u64,
+  slot: u64,
+  index: u64,
+  instruction_index: u8,
+  signers: &HashSet<Pubkey>,
+  signature: &Signature,
+) -> Option<RouteTrade> {
+  match router {
+    Router::JupiterV6 => process_jupiter_route_instruction(
+      instruction,
+      inner_instructions,
+      child_swaps,
+      block_time,
+      slot,
+      index,
+      instruction_index,
+      signers,
+      signature,
+    ),
+    Router::Okx | Router::DFlow | Router::Titan => collapse_route_swaps(
+      router,
+      child_swaps,
+      block_time,
+      slot,
+      index,
+      instruction_index,
+      signers,
+      signature,
+    ),
+  }
+}
@@ -0,0 +1,20 @@
+use crate::utilities::swap_tokens::swap_tokens;
+use solana_central::SwapTx;
+use solana_sdk::pubkey::Pubkey;
+
+/// Route legs of decoded swaps in the form `collapse_route_legs` takes,
+/// `(input mint, input amount, output mint, output amount)` per swap in execution order.
+pub fn swap_route_legs(swaps: &[SwapTx]) -> Vec<(Pubkey, u64, Pubkey, u64)> {
+  swaps
+    .iter()
+    .map(|swap| {
+      let (token_in, token_out) = swap_tokens(swap);
+      (
+        token_in,
+        swap.swapped_amount_in,
+        token_out,
+        swap.swapped_amount_received,
+      )
+    })
+    .collect()
+}
@@ -0,0 +1,22 @@
+use crate::constants::system_program::SYSTEM_PROGRAM_ID;
+use crate::constants::system_program::SYSTEM_TRANSFER_TAG;
+use solana_central::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+/// Decode a System program `Transfer` instruction into the source account, destination account
+/// and lamports transferred. Returns `None` for any other instruction.
+pub fn decode_system_transfer(instruction: &Instruction) -> Option<(Pubkey, Pubkey, u64)> {
+  if instruction.tx_account_keys[instruction.program_id_index as usize] != SYSTEM_PROGRAM_ID
+    || instruction.data.len() < 12
+    || instruction.accounts.len() < 2
+    || u32::from_le_bytes(instruction.data[0..4].try_into().unwrap()) != SYSTEM_TRANSFER_TAG
+  {
+    return None;
+  }
+  let lamports = u64::from_le_bytes(instruction.data[4..12].try_into().unwrap());
+  Some((
+    instruction.tx_account_keys[instruction.accounts[0] as usize],
+    instruction.tx_account_keys[instruction.accounts[1] as usize],
+    lamports,
+  ))
+}
@@ -0,0 +1 @@
+pub mod decode_system_transfer;
@@ -0,0 +1,144 @@
+use crate::constants::token_program::TOKEN_2022_PROGRAM_ID;
+use crate::constants::token_program::TOKEN_2022_TRANSFER_CHECKED_WITH_FEE_TAG;
+use crate::constants::token_program::TOKEN_2022_TRANSFER_FEE_EXTENSION_TAG;
+use crate::constants::token_program::TOKEN_PROGRAM_ID;
+use crate::constants::token_program::TOKEN_TRANSFER_CHECKED_TAG;
+use crate::constants::token_program::TOKEN_TRANSFER_TAG;
+use crate::types::token_transfer::TokenTransfer;
+use solana_central::Instruction;
+
+/// Decode an SPL Token or Token-2022 `Transfer`, `TransferChecked` or Token-2022
+/// `TransferCheckedWithFee` instruction. Returns `None` for any other instruction.
+pub fn decode_token_transfer(instruction: &Instruction) -> Option<TokenTransfer> {
+  let program_id = *instruction
+    .tx_account_keys
+    .get(instruction.program_id_index as usize)?;
+  let is_token_2022 = program_id == TOKEN_2022_PROGRAM_ID;
+  if !(is_token_2022 || program_id == TOKEN_PROGRAM_ID) || instruction.data.is_empty() {
+    return None;
+  }
+  let data = instruction.data;
+  let accounts = &instruction.accounts;
+  // Transfer accounts are source, destination, authority
+  if data[0] == TOKEN_TRANSFER_TAG && data.len() >= 9 && accounts.len() >= 2 {
+    return Some(TokenTransfer {
+      source: accounts[0],
+      destination: accounts[1],
+      amount: u64::from_le_bytes(data[1..9].try_into().unwrap()),
+      fee: None,
+      is_token_2022,
+    });
+  }
+  // TransferChecked accounts are source, mint, destination, authority
+  if data[0] == TOKEN_TRANSFER_CHECKED_TAG && data.len() >= 10 && accounts.len() >= 3 {
+    return Some(TokenTransfer {
+      source: accounts[0],
+      destination: accounts[2],
+      amount: u64::from_le_bytes(data[1..9].try_into().unwrap()),
+      fee: None,
+      is_token_2022,
+    });
+  }
+  // TransferCheckedWithFee has the same accounts as TransferChecked, data is the extension tag,
+  // the instruction tag, amount, decimals and fee
+  if is_token_2022
+    && data[0] == TOKEN_2022_TRANSFER_FEE_EXTENSION_TAG
+    && data.len() >= 19
+    && data[1] == TOKEN_2022_TRANSFER_CHECKED_WITH_FEE_TAG
+    && accounts.len() >= 3
+  {
+    return Some(TokenTransfer {
+      source: accounts[0],
+      destination: accounts[2],
+      amount: u64::from_le_bytes(data[2..10].try_into().unwrap()),
+      fee: Some(u64::from_le_bytes(data[11..19].try_into().unwrap())),
+      is_token_2022,
+    });
+  }
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use solana_sdk::pubkey::Pubkey;
+
+  fn decode(program_id: Pubkey, accounts: Vec<u8>, data: Vec<u8>) -> Option<TokenTransfer> {
+    let account_keys = vec![program_id];
+    let instruction = Instruction {
+      tx_account_keys: &account_keys,
+      accounts: &accounts,
+      data: &data,
+      program_id_index: 0,
+    };
+    decode_token_transfer(&instruction)
+  }
+
+  fn tagged(tags: &[u8], fields: &[&[u8]]) -> Vec<u8> {
+    let mut data = tags.to_vec();
+    for field in fields {
+      data.extend_from_slice(field);
+    }
+    data
+  }
+
+  #[test]
+  fn decodes_transfer() {
+    let data = tagged(&[TOKEN_TRANSFER_TAG], &[&500u64.to_le_bytes()]);
+    let transfer = decode(TOKEN_PROGRAM_ID, vec![4, 5, 6], data).unwrap();
+    let expected = TokenTransfer {
+      source: 4,
+      destination: 5,
+      amount: 500,
+      fee: None,
+      is_token_2022: false,
+    };
+    assert_eq!(transfer, expected);
+  }
+
+  #[test]
+  fn decodes_transfer_checked() {
+    let data = tagged(
+      &[TOKEN_TRANSFER_CHECKED_TAG],
+      &[&500u64.to_le_bytes(), &[6]],
+    );
+    let transfer = decode(TOKEN_2022_PROGRAM_ID, vec![4, 9, 5, 6], data).unwrap();
+    assert_eq!(transfer.source, 4);
+    assert_eq!(transfer.destination, 5);
+    assert_eq!(transfer.amount, 500);
+    assert_eq!(transfer.fee, None);
+    assert!(transfer.is_token_2022);
+  }
+
+  #[test]
+  fn decodes_transfer_checked_with_fee() {
+    let data = tagged(
+      &[
+        TOKEN_2022_TRANSFER_FEE_EXTENSION_TAG,
+        TOKEN_2022_TRANSFER_CHECKED_WITH_FEE_TAG,
+      ],
+      &[&500u64.to_le_bytes(), &[6], &5u64.to_le_bytes()],
+    );
+    let transfer = decode(TOKEN_2022_PROGRAM_ID, vec![4, 9, 5, 6], data.clone()).unwrap();
+    assert_eq!(transfer.destination, 5);
+    assert_eq!(transfer.amount, 500);
+    assert_eq!(transfer.fee, Some(5));
+    assert_eq!(transfer.amount_after_fee(), 495);
+    // The transfer fee extension only exists in Token-2022
+    assert!(decode(TOKEN_PROGRAM_ID, vec![4, 9, 5, 6], data).is_none());
+  }
+
+  #[test]
+  fn rejects_other_programs_and_short_instructions() {
+    let data = tagged(&[TOKEN_TRANSFER_TAG], &[&500u64.to_le_bytes()]);
+    assert!(decode(Pubkey::new_unique(), vec![4, 5, 6], data.clone()).is_none());
+    assert!(decode(TOKEN_PROGRAM_ID, vec![4], data.clone()).is_none());
+    assert!(decode(TOKEN_PROGRAM_ID, vec![4, 5, 6], data[..8].to_vec()).is_none());
+    assert!(decode(TOKEN_PROGRAM_ID, vec![4, 5, 6], Vec::new()).is_none());
+    let data = tagged(
+      &[TOKEN_TRANSFER_CHECKED_TAG],
+      &[&500u64.to_le_bytes(), &[6]],
+    );
+    assert!(decode(TOKEN_PROGRAM_ID, vec![4, 5], data).is_none());
+  }
+}
@@ -0,0 +1 @@
+pub mod decode_token_transfer;
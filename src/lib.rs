@@ -3,27 +3,87 @@
 //! Library for decoding raw Solana transactions from various sources into standardized formats.
 //!
 //! This library provides:
-//! - Multi-format transaction parsing (Archive, gRPC, JSON RPC)
+//! - Multi-format transaction parsing (Archive, gRPC, JSON RPC, raw wire bytes)
+//! - Per-transaction `TxCost` (base fee, priority fee, compute budget, compute units consumed and
+//!   block engine tips) and probable bundle grouping
+//! - Aggregator route collapsing into user level `RouteTrade`s (Jupiter v6, OKX, DFlow, Titan)
+//! - Per-signer net SOL and token balance changes (`WalletDelta`)
+//! - Opt-in reconciliation of running token balances against post balances (`BalanceMismatch`)
+//! - Atomic arbitrage cycle detection within a transaction (`ArbitrageEvent`)
+//! - Block level sandwich attack detection over decoded swaps (`detect_sandwiches`)
+//! - Opt-in fallback decoding of events from "Program data" program logs
+//! - Dead-letter output for instructions of supported programs that can't be decoded
+//!   (`UnrecognizedInstruction`)
+//! - Opt-in decoding of failed transactions into `FailedSwapAttempt`s
+//! - Pre-execution decoding of unconfirmed transactions without status meta (`analyze_pending_tx`)
 //! - Instruction classification and decoding
+//! - Watchlist filtering by mint, market, program, signer and SOL size (`DecodeFilter`)
+//! - Yellowstone transaction subscriptions matching the enabled decoders
+//!   (`build_transactions_subscribe_filter`)
+//! - Optional Yellowstone gRPC stream driver releasing output per slot at a chosen commitment with
+//!   rollback notices for dead and skipped slots (`grpc` feature)
+//! - Optional decoding counters and stage timings in Prometheus format (`metrics` feature)
 //! - Parallel processing ability with Tokio broadcast channels
-//! - Standardized output types (`SwapTx`, `TokenCreation`)
-//! - Protocol-specific instruction processors (Raydium, Pumpswap, Pumpfun)
+//! - Standardized output types (`SwapTx`, `TokenCreation`) plus `SwapDetails` decoded from swap
+//!   instruction arguments
+//! - Protocol-specific instruction processors (Raydium, Pumpswap, Pumpfun, Jupiter Perps)
 //!
 //! ## Usage
 //!
 //! The main entry point is `analyze_tx`, which takes a `TxFormat` enum (containing any transaction
-//! format) and writes decoded swap transactions and token creations to broadcast channels.
+//! format) and writes decoded swap transactions and token creations to the broadcast channels in
+//! `DecodeSenders`. Other transaction sources can be decoded by implementing `TxSource` and calling
+//! `analyze_tx_source`, or `decode_tx_source` to get the `DecodedTx` without sending it.
 
+mod address_lookup_table;
+mod block;
+mod constants;
+#[cfg(feature = "grpc")]
+mod grpc;
 mod instruction;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod protocol_idls;
 mod tx;
+mod tx_sources;
 mod types;
 mod utilities;
 
 // Re-exports
 pub use tx::analyze_tx::analyze_tx;
+pub use tx::analyze_tx_source::analyze_tx_source;
+pub use tx::analyze_pending_tx::analyze_pending_tx;
+pub use tx::analyze_failed_tx_source::analyze_failed_tx_source;
+pub use tx::decode_tx_source::decode_tx_source;
+pub use tx::decode_failed_tx_source::decode_failed_tx_source;
 pub use tx::top_level_instructions_loop::top_level_instructions_loop;
 pub use tx::inner_instructions_loop::inner_instructions_loop;
+pub use tx::send_decoded_tx::send_decoded_tx;
+pub use tx::apply_decode_filter::apply_decode_filter;
+pub use tx::apply_swap_decimals::apply_swap_decimals;
+pub use tx::compute_tx_cost::compute_tx_cost;
+pub use tx::compute_wallet_deltas::compute_wallet_deltas;
+pub use tx::find_tips::find_tips;
+pub use tx::detect_arbitrage::detect_arbitrage;
+pub use tx::parse_program_logs::parse_program_logs;
+pub use tx::process_log_events::process_log_events;
+pub use tx::reconcile_running_token_balances::reconcile_running_token_balances;
+pub use block::group_probable_bundles::group_probable_bundles;
+pub use block::detect_sandwiches::detect_sandwiches;
+pub use instruction::compute_budget::decode_compute_budget::decode_compute_budget;
+pub use instruction::system::decode_system_transfer::decode_system_transfer;
+pub use instruction::token::decode_token_transfer::decode_token_transfer;
+pub use instruction::router::classify_router_instruction::classify_router_instruction;
+pub use instruction::router::process_router_instruction::process_router_instruction;
+pub use instruction::router::process_jupiter_route_instruction::process_jupiter_route_instruction;
+pub use instruction::router::collapse_route_swaps::collapse_route_swaps;
+pub use instruction::router::collapse_route_legs::collapse_route_legs;
 pub use instruction::classify_instruction::classify_instruction;
+pub use instruction::classify_pending_instruction::classify_pending_instruction;
+pub use instruction::classify_swap_failure::classify_swap_failure;
+pub use instruction::process_pending_swap_instruction::process_pending_swap_instruction;
+pub use instruction::process_unrecognized_instruction::process_unrecognized_instruction;
+pub use instruction::resolve_event_layout::resolve_event_layout;
 pub use instruction::raydium::process_raydium_ammv4_swap_instruction::process_raydium_ammv4_swap_instruction;
 pub use instruction::raydium::process_raydium_cpmm_swap_instruction::process_raydium_cpmm_swap_instruction;
 pub use instruction::raydium::process_raydium_launchpad_swap_instruction::process_raydium_launchpad_swap_instruction;
@@ -36,9 +96,80 @@ pub use instruction::raydium::is_raydium_launchpad_swap_instruction::is_raydium_
 pub use instruction::pumpswap::is_pumpswap_swap_instruction::is_pumpswap_swap_instruction;
 pub use instruction::pumpfun::is_pumpfun_event_instruction::is_pumpfun_event_instruction;
 pub use instruction::pumpfun::is_pf_bonding_curve_create_instruction::is_pf_bonding_curve_create_instruction;
+pub use instruction::pumpfun::is_pf_bonding_curve_swap_instruction::is_pf_bonding_curve_swap_instruction;
+pub use instruction::pumpfun::find_pf_bonding_curve_swap_instruction::find_pf_bonding_curve_swap_instruction;
+pub use instruction::jupiter_perps::is_jupiter_perps_event_instruction::is_jupiter_perps_event_instruction;
+pub use instruction::jupiter_perps::process_jupiter_perps_event_instruction::process_jupiter_perps_event_instruction;
+pub use instruction::raydium::decode_raydium_ammv4_swap_intent::decode_raydium_ammv4_swap_intent;
+pub use instruction::raydium::decode_raydium_cpmm_swap_intent::decode_raydium_cpmm_swap_intent;
+pub use instruction::raydium::decode_raydium_launchpad_swap_intent::decode_raydium_launchpad_swap_intent;
+pub use instruction::pumpswap::decode_pumpswap_swap_intent::decode_pumpswap_swap_intent;
+pub use instruction::pumpfun::decode_pf_bonding_curve_swap_intent::decode_pf_bonding_curve_swap_intent;
 pub use types::tx_format::TxFormat;
 pub use types::instruction_type::InstructionType;
 pub use types::archive_tx::ArchiveTx;
 pub use types::grpc_tx::GrpcTx;
 pub use types::json_rpc_tx::JsonRpcTx;
-pub use utilities::fetch_token_metadata_from_uri::fetch_token_metadata_from_uri;
\ No newline at end of file
+pub use types::raw_tx::RawTx;
+pub use types::raw_tx::RawTxMeta;
+pub use types::decode_options::DecodeOptions;
+pub use types::decode_filter::DecodeFilter;
+pub use types::decoder::Decoder;
+pub use types::decode_senders::DecodeSenders;
+pub use types::decoded_tx::DecodedTx;
+pub use types::inner_instruction::InnerInstruction;
+pub use types::token_balance::TokenBalance;
+pub use types::token_transfer::TokenTransfer;
+pub use types::swap_fees::SwapFees;
+pub use types::event_layout::EventLayout;
+pub use types::event_layout_entry::EventLayoutEntry;
+pub use types::tx_cost::TxCost;
+pub use types::wallet_delta::WalletDelta;
+pub use types::running_token_balances::RunningTokenBalances;
+pub use types::balance_mismatch::BalanceMismatch;
+pub use types::unrecognized_instruction::UnrecognizedInstruction;
+pub use types::log_event::LogEvent;
+pub use types::program_logs::ProgramLogs;
+pub use types::log_truncation::LogTruncation;
+pub use types::slot_rollback::SlotRollback;
+pub use types::slot_rollback_reason::SlotRollbackReason;
+pub use types::tip::Tip;
+pub use types::probable_bundle::ProbableBundle;
+pub use types::sandwich_event::SandwichEvent;
+pub use types::sandwich_victim::SandwichVictim;
+pub use types::arbitrage_event::ArbitrageEvent;
+pub use types::route_trade::RouteTrade;
+pub use types::router::Router;
+pub use types::perp_event::PerpEvent;
+pub use types::perp_event_kind::PerpEventKind;
+pub use types::perp_side::PerpSide;
+pub use constants::tip_accounts::JITO_TIP_ACCOUNTS;
+pub use constants::event_layouts::EVENT_LAYOUTS;
+pub use tx_sources::tx_source::TxSource;
+pub use tx_sources::raw_tx_source::RawTxSource;
+pub use types::pending_swap::PendingSwap;
+pub use types::failed_swap_attempt::FailedSwapAttempt;
+pub use types::swap_failure_kind::SwapFailureKind;
+pub use types::swap_details::SwapDetails;
+pub use types::swap_intent::SwapIntent;
+pub use types::swap_mode::SwapMode;
+pub use address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+pub use address_lookup_table::in_memory_address_lookup_table_cache::InMemoryAddressLookupTableCache;
+pub use address_lookup_table::resolve_address_table_lookups::resolve_address_table_lookups;
+pub use utilities::fetch_token_metadata_from_uri::fetch_token_metadata_from_uri;
+pub use utilities::compute_slippage_tolerance_bps::compute_slippage_tolerance_bps;
+pub use utilities::constant_product_amount_out::constant_product_amount_out;
+pub use utilities::decode_event_prefix::decode_event_prefix;
+pub use utilities::build_transactions_subscribe_filter::build_transactions_subscribe_filter;
+#[cfg(feature = "metrics")]
+pub use metrics::decode_metrics::DecodeMetrics;
+#[cfg(feature = "metrics")]
+pub use metrics::decode_metrics::DECODE_METRICS;
+#[cfg(feature = "metrics")]
+pub use metrics::decode_stage::DecodeStage;
+#[cfg(feature = "metrics")]
+pub use metrics::timing_histogram::TimingHistogram;
+#[cfg(feature = "grpc")]
+pub use grpc::grpc_driver::GrpcDriver;
+#[cfg(feature = "grpc")]
+pub use grpc::grpc_driver_options::GrpcDriverOptions;
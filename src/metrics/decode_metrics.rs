@@ -0,0 +1,244 @@
+use crate::metrics::decode_stage::DecodeStage;
+use crate::metrics::timing_histogram::TimingHistogram;
+use crate::types::decoded_tx::DecodedTx;
+use solana_central::Pools;
+use std::fmt::Write;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+/// `pool` label of the swap counters, indexed by `swap_pool_index`
+const SWAP_POOL_LABELS: [&str; 6] = [
+  "RaydiumAmmV4",
+  "RaydiumCpmm",
+  "RaydiumLaunchpad",
+  "PumpswapAmm",
+  "PfBondingCurve",
+  "Other",
+];
+
+/// Index of the swap counter of `pool`, pools without their own counter share the last one
+fn swap_pool_index(pool: &Pools) -> usize {
+  if matches!(pool, Pools::RaydiumAmmV4) {
+    0
+  } else if matches!(pool, Pools::RaydiumCpmm) {
+    1
+  } else if matches!(pool, Pools::RaydiumLaunchpad) {
+    2
+  } else if matches!(pool, Pools::PumpswapAmm) {
+    3
+  } else if matches!(pool, Pools::PfBondingCurve) {
+    4
+  } else {
+    SWAP_POOL_LABELS.len() - 1
+  }
+}
+
+/// Process wide decoding counters and stage timings, recorded by `decode_tx_source` into
+/// `DECODE_METRICS`. Serve `encode_prometheus` from a metrics endpoint to scrape them.
+pub struct DecodeMetrics {
+  txs_seen: AtomicU64,
+  /// Skipped because their bytes or account keys could not be decoded, or the decode filter
+  /// pre-check ruled them out
+  txs_skipped: AtomicU64,
+  txs_failed: AtomicU64,
+  /// Swaps decoded per pool, indexed by `swap_pool_index`
+  swaps: [AtomicU64; SWAP_POOL_LABELS.len()],
+  token_creations: AtomicU64,
+  /// Instructions with unknown layouts, emitted as `UnrecognizedInstruction`s
+  unrecognized_instructions: AtomicU64,
+  balance_mismatches: AtomicU64,
+  log_truncations: AtomicU64,
+  stages: [TimingHistogram; DecodeStage::ALL.len()],
+}
+
+pub static DECODE_METRICS: DecodeMetrics = DecodeMetrics::new();
+
+impl DecodeMetrics {
+  pub const fn new() -> Self {
+    DecodeMetrics {
+      txs_seen: AtomicU64::new(0),
+      txs_skipped: AtomicU64::new(0),
+      txs_failed: AtomicU64::new(0),
+      swaps: [const { AtomicU64::new(0) }; SWAP_POOL_LABELS.len()],
+      token_creations: AtomicU64::new(0),
+      unrecognized_instructions: AtomicU64::new(0),
+      balance_mismatches: AtomicU64::new(0),
+      log_truncations: AtomicU64::new(0),
+      stages: [const { TimingHistogram::new() }; DecodeStage::ALL.len()],
+    }
+  }
+
+  pub fn record_tx_seen(&self) {
+    self.txs_seen.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn record_tx_skipped(&self) {
+    self.txs_skipped.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn record_tx_failed(&self) {
+    self.txs_failed.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn record_stage(&self, stage: DecodeStage, duration: Duration) {
+    self.stages[stage as usize].record(duration);
+  }
+
+  /// Record the time since `stage_start` for `stage` and restart it for the next stage
+  pub fn finish_stage(&self, stage: DecodeStage, stage_start: &mut Instant) {
+    let now = Instant::now();
+    self.record_stage(stage, now - *stage_start);
+    *stage_start = now;
+  }
+
+  /// Count the output of a decoded transaction
+  pub fn record_decoded_tx(&self, decoded_tx: &DecodedTx) {
+    for swap_tx in &decoded_tx.swaps {
+      self.swaps[swap_pool_index(&swap_tx.pool)].fetch_add(1, Ordering::Relaxed);
+    }
+    let add = |counter: &AtomicU64, len: usize| {
+      if len > 0 {
+        counter.fetch_add(len as u64, Ordering::Relaxed);
+      }
+    };
+    add(&self.token_creations, decoded_tx.token_creations.len());
+    add(
+      &self.unrecognized_instructions,
+      decoded_tx.unrecognized_instructions.len(),
+    );
+    add(
+      &self.balance_mismatches,
+      decoded_tx.balance_mismatches.len(),
+    );
+    add(&self.log_truncations, decoded_tx.log_truncations.len());
+  }
+
+  /// Encode all metrics in the Prometheus text exposition format.
+  pub fn encode_prometheus(&self) -> String {
+    let mut out = String::new();
+    let counter = |out: &mut String, name: &str, help: &str, value: &AtomicU64| {
+      let _ = writeln!(out, "# HELP {} {}", name, help);
+      let _ = writeln!(out, "# TYPE {} counter", name);
+      let _ = writeln!(out, "{} {}", name, value.load(Ordering::Relaxed));
+    };
+    counter(
+      &mut out,
+      "solana_tx_decoding_txs_seen_total",
+      "Transactions passed to the decoder",
+      &self.txs_seen,
+    );
+    counter(
+      &mut out,
+      "solana_tx_decoding_txs_skipped_total",
+      "Transactions skipped as undecodable or ruled out by the decode filter",
+      &self.txs_skipped,
+    );
+    counter(
+      &mut out,
+      "solana_tx_decoding_txs_failed_total",
+      "Failed transactions",
+      &self.txs_failed,
+    );
+    counter(
+      &mut out,
+      "solana_tx_decoding_token_creations_total",
+      "Token creations decoded",
+      &self.token_creations,
+    );
+    counter(
+      &mut out,
+      "solana_tx_decoding_unrecognized_instructions_total",
+      "Instructions of supported programs with unknown layouts",
+      &self.unrecognized_instructions,
+    );
+
+    let _ = writeln!(
+      out,
+      "# HELP solana_tx_decoding_swaps_total Swaps decoded per pool"
+    );
+    let _ = writeln!(out, "# TYPE solana_tx_decoding_swaps_total counter");
+    for (pool, count) in SWAP_POOL_LABELS.iter().zip(&self.swaps) {
+      let _ = writeln!(
+        out,
+        "solana_tx_decoding_swaps_total{{pool=\"{}\"}} {}",
+        pool,
+        count.load(Ordering::Relaxed)
+      );
+    }
+
+    let _ = writeln!(
+      out,
+      "# HELP solana_tx_decoding_decode_errors_total Decoded output found to be inconsistent or incomplete"
+    );
+    let _ = writeln!(out, "# TYPE solana_tx_decoding_decode_errors_total counter");
+    let _ = writeln!(
+      out,
+      "solana_tx_decoding_decode_errors_total{{kind=\"balance_mismatch\"}} {}",
+      self.balance_mismatches.load(Ordering::Relaxed)
+    );
+    let _ = writeln!(
+      out,
+      "solana_tx_decoding_decode_errors_total{{kind=\"log_truncated\"}} {}",
+      self.log_truncations.load(Ordering::Relaxed)
+    );
+
+    let name = "solana_tx_decoding_stage_duration_seconds";
+    let _ = writeln!(out, "# HELP {} Time spent per decoding stage", name);
+    let _ = writeln!(out, "# TYPE {} histogram", name);
+    for stage in DecodeStage::ALL {
+      let labels = format!("stage=\"{}\",", stage.label());
+      self.stages[stage as usize].encode(name, &labels, &mut out);
+    }
+    out
+  }
+}
+
+impl Default for DecodeMetrics {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utilities::test_swap_tx_builder::TestSwapTxBuilder;
+
+  #[test]
+  fn counts_swaps_per_pool() {
+    let metrics = DecodeMetrics::new();
+    let decoded_tx = DecodedTx {
+      swaps: vec![
+        TestSwapTxBuilder::default().build(),
+        TestSwapTxBuilder::default().build(),
+        TestSwapTxBuilder::default()
+          .pool(Pools::PumpswapAmm)
+          .build(),
+      ],
+      ..Default::default()
+    };
+    metrics.record_decoded_tx(&decoded_tx);
+    metrics.record_decoded_tx(&decoded_tx);
+
+    let out = metrics.encode_prometheus();
+    assert!(out.contains("solana_tx_decoding_swaps_total{pool=\"RaydiumCpmm\"} 4\n"));
+    assert!(out.contains("solana_tx_decoding_swaps_total{pool=\"PumpswapAmm\"} 2\n"));
+    assert!(out.contains("solana_tx_decoding_swaps_total{pool=\"RaydiumAmmV4\"} 0\n"));
+  }
+
+  #[test]
+  fn counts_skipped_and_failed_txs() {
+    let metrics = DecodeMetrics::new();
+    metrics.record_tx_seen();
+    metrics.record_tx_seen();
+    metrics.record_tx_skipped();
+    metrics.record_tx_failed();
+
+    let out = metrics.encode_prometheus();
+    assert!(out.contains("solana_tx_decoding_txs_seen_total 2\n"));
+    assert!(out.contains("solana_tx_decoding_txs_skipped_total 1\n"));
+    assert!(out.contains("solana_tx_decoding_txs_failed_total 1\n"));
+  }
+}
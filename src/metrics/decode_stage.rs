@@ -0,0 +1,34 @@
+/// Stages of `decode_tx_source` that are timed separately. `Send` is timed by `analyze_tx_source`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeStage {
+  /// Account keys, instructions, token balances, cost and wallet deltas
+  Prepare,
+  /// Top level and inner instruction loops
+  Instructions,
+  /// Log event parsing and decoding, only when `decode_log_events` is set
+  LogEvents,
+  /// Decimals, arbitrage detection and balance reconciliation
+  PostProcess,
+  /// Writing decoded output to the broadcast channels
+  Send,
+}
+
+impl DecodeStage {
+  pub const ALL: [DecodeStage; 5] = [
+    DecodeStage::Prepare,
+    DecodeStage::Instructions,
+    DecodeStage::LogEvents,
+    DecodeStage::PostProcess,
+    DecodeStage::Send,
+  ];
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      DecodeStage::Prepare => "prepare",
+      DecodeStage::Instructions => "instructions",
+      DecodeStage::LogEvents => "log_events",
+      DecodeStage::PostProcess => "post_process",
+      DecodeStage::Send => "send",
+    }
+  }
+}
@@ -0,0 +1,3 @@
+pub mod decode_metrics;
+pub mod decode_stage;
+pub mod timing_histogram;
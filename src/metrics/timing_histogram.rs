@@ -0,0 +1,110 @@
+use std::fmt::Write;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+/// Upper bounds of the histogram buckets in microseconds, a final +Inf bucket is implied
+pub const TIMING_BUCKETS_MICROS: [u64; 10] = [5, 10, 25, 50, 100, 250, 500, 1000, 5000, 25000];
+
+/// Lock free histogram of durations with fixed buckets, encoded as a Prometheus histogram in
+/// seconds.
+pub struct TimingHistogram {
+  /// Non cumulative counts per bucket, the last one counts durations above every bound
+  buckets: [AtomicU64; TIMING_BUCKETS_MICROS.len() + 1],
+  sum_micros: AtomicU64,
+  count: AtomicU64,
+}
+
+impl TimingHistogram {
+  pub const fn new() -> Self {
+    TimingHistogram {
+      buckets: [const { AtomicU64::new(0) }; TIMING_BUCKETS_MICROS.len() + 1],
+      sum_micros: AtomicU64::new(0),
+      count: AtomicU64::new(0),
+    }
+  }
+
+  pub fn record(&self, duration: Duration) {
+    let micros = duration.as_micros() as u64;
+    let bucket = TIMING_BUCKETS_MICROS
+      .iter()
+      .position(|bound| micros <= *bound)
+      .unwrap_or(TIMING_BUCKETS_MICROS.len());
+    self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    self.sum_micros.fetch_add(micros, Ordering::Relaxed);
+    self.count.fetch_add(1, Ordering::Relaxed);
+  }
+
+  /// Append the bucket, sum and count series of this histogram to `out`. `labels` is inserted in
+  /// front of the `le` label, e.g. `stage="send",`
+  pub fn encode(&self, name: &str, labels: &str, out: &mut String) {
+    let mut cumulative = 0;
+    for (bucket, bound) in TIMING_BUCKETS_MICROS.iter().enumerate() {
+      cumulative += self.buckets[bucket].load(Ordering::Relaxed);
+      let _ = writeln!(
+        out,
+        "{}_bucket{{{}le=\"{}\"}} {}",
+        name,
+        labels,
+        *bound as f64 / 1_000_000.0,
+        cumulative
+      );
+    }
+    cumulative += self.buckets[TIMING_BUCKETS_MICROS.len()].load(Ordering::Relaxed);
+    let _ = writeln!(
+      out,
+      "{}_bucket{{{}le=\"+Inf\"}} {}",
+      name, labels, cumulative
+    );
+    let sum_seconds = self.sum_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+    let labels = labels.trim_end_matches(',');
+    let _ = writeln!(out, "{}_sum{{{}}} {}", name, labels, sum_seconds);
+    let _ = writeln!(
+      out,
+      "{}_count{{{}}} {}",
+      name,
+      labels,
+      self.count.load(Ordering::Relaxed)
+    );
+  }
+}
+
+impl Default for TimingHistogram {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn encodes_cumulative_buckets() {
+    let histogram = TimingHistogram::new();
+    histogram.record(Duration::from_micros(3));
+    histogram.record(Duration::from_millis(30));
+
+    let mut out = String::new();
+    histogram.encode("t", "stage=\"send\",", &mut out);
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines.len(), TIMING_BUCKETS_MICROS.len() + 3);
+    assert_eq!(lines[0], "t_bucket{stage=\"send\",le=\"0.000005\"} 1");
+    assert_eq!(lines[9], "t_bucket{stage=\"send\",le=\"0.025\"} 1");
+    assert_eq!(lines[10], "t_bucket{stage=\"send\",le=\"+Inf\"} 2");
+    assert_eq!(lines[11], "t_sum{stage=\"send\"} 0.030003");
+    assert_eq!(lines[12], "t_count{stage=\"send\"} 2");
+  }
+
+  #[test]
+  fn bounds_are_inclusive() {
+    let histogram = TimingHistogram::new();
+    histogram.record(Duration::from_micros(10));
+
+    let mut out = String::new();
+    histogram.encode("t", "", &mut out);
+    assert!(out.contains("t_bucket{le=\"0.000005\"} 0\n"));
+    assert!(out.contains("t_bucket{le=\"0.00001\"} 1\n"));
+    assert!(out.contains("t_count{} 1\n"));
+  }
+}
@@ -0,0 +1,27 @@
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(BorshDeserialize, Debug)]
+pub struct JupiterPerpsDecreasePositionEventIdl {
+  pub position_key: Pubkey,
+  pub position_side: u8,
+  pub position_custody: Pubkey,
+  pub position_collateral_custody: Pubkey,
+  pub position_size_usd: u64,
+  pub position_mint: Pubkey,
+  pub position_request_key: Pubkey,
+  pub position_request_mint: Pubkey,
+  pub position_request_change: u8,
+  pub position_request_type: u8,
+  pub pool: Pubkey,
+  pub owner: Pubkey,
+  pub size_usd_delta: u64,
+  pub collateral_usd_delta: u64,
+  pub has_profit: bool,
+  pub pnl_delta: u64,
+  pub transfer_amount_usd: u64,
+  pub transfer_token: Option<u64>,
+  pub price: u64,
+  pub price_slippage: Option<u64>,
+  pub fee_usd: u64,
+}
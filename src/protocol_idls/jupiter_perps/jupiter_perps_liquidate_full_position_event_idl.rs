@@ -0,0 +1,20 @@
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(BorshDeserialize, Debug)]
+pub struct JupiterPerpsLiquidateFullPositionEventIdl {
+  pub position_key: Pubkey,
+  pub position_side: u8,
+  pub position_custody: Pubkey,
+  pub position_collateral_custody: Pubkey,
+  pub position_collateral_mint: Pubkey,
+  pub position_mint: Pubkey,
+  pub position_size_usd: u64,
+  pub has_profit: bool,
+  pub pnl_delta: u64,
+  pub transfer_amount_usd: u64,
+  pub transfer_token: u64,
+  pub price: u64,
+  pub fee_usd: u64,
+  pub liquidation_fee_usd: u64,
+}
@@ -0,0 +1,9 @@
+/*
+Jupiter Perps event layouts, without the 16 byte Anchor event CPI prefix. Only the leading fields
+that are decoded are listed, fields appended by program upgrades are ignored.
+*/
+pub mod jupiter_perps_decrease_position_event_idl;
+pub mod jupiter_perps_increase_position_event_idl;
+pub mod jupiter_perps_instant_decrease_position_event_idl;
+pub mod jupiter_perps_instant_increase_position_event_idl;
+pub mod jupiter_perps_liquidate_full_position_event_idl;
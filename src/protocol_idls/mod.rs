@@ -0,0 +1 @@
+pub mod jupiter_perps;
@@ -0,0 +1,21 @@
+use crate::tx::decode_failed_tx_source::decode_failed_tx_source;
+use crate::tx::send_decoded_tx::send_decoded_tx;
+use crate::tx_sources::tx_source::TxSource;
+use crate::types::decode_options::DecodeOptions;
+use crate::types::decode_senders::DecodeSenders;
+
+/// Analyze a failed transaction from any `TxSource` and write its `FailedSwapAttempt`s and cost to
+/// the channels in `senders`, whether or not `decode_failed_txs` is set in `options`. See
+/// `decode_failed_tx_source` for how the attempts are decoded.
+pub fn analyze_failed_tx_source<S: TxSource + ?Sized>(
+  source: &S,
+  senders: &DecodeSenders,
+  options: &DecodeOptions,
+  block_time: u64,
+  slot: u64,
+  index: u64,
+) {
+  if let Some(decoded_tx) = decode_failed_tx_source(source, options, block_time, slot, index) {
+    send_decoded_tx(decoded_tx, senders);
+  }
+}
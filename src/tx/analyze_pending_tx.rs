@@ -0,0 +1,110 @@
+use crate::address_lookup_table::resolve_address_table_lookups::resolve_address_table_lookups;
+use crate::instruction::classify_pending_instruction::classify_pending_instruction;
+use crate::instruction::process_pending_swap_instruction::process_pending_swap_instruction;
+use crate::instruction::pumpfun::process_pf_bonding_curve_create_instruction::process_pf_bonding_curve_create_instruction;
+use crate::tx::send_decoded_tx::send_decoded_tx;
+use crate::types::decode_options::DecodeOptions;
+use crate::types::decode_senders::DecodeSenders;
+use crate::types::decoded_tx::DecodedTx;
+use crate::types::instruction_type::InstructionType;
+use solana_central::Instruction;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::VersionedTransaction;
+use std::collections::HashSet;
+
+/// Analyze an unconfirmed transaction that has no status meta, e.g. from shred or mempool style
+/// feeds. Only top level instructions are looked at since inner instructions, events and token
+/// balances are not available. Swaps are written as `PendingSwap`s built from instruction arguments
+/// and token creations as `TokenCreation`s to the channels in `senders`. `received_time` is used
+/// as block time and the position in block is unknown so `index` on token creations is 0.
+/// Accounts loaded from address lookup tables are resolved with the resolver in `options`, if the
+/// tables can't be resolved instructions referencing them are skipped. A `filter` in `options` is
+/// used as a pre-check on the account keys, so watchlist mints have to be account keys of the
+/// transaction, and on each `PendingSwap` and `TokenCreation` before it is sent.
+pub fn analyze_pending_tx(
+  tx: &VersionedTransaction,
+  senders: &DecodeSenders,
+  options: &DecodeOptions,
+  received_time: u64,
+  slot: u64,
+) {
+  let mut account_keys = tx.message.static_account_keys().to_vec();
+  if let (Some(lookups), Some(resolver)) = (
+    tx.message.address_table_lookups(),
+    options.address_lookup_table_resolver,
+  ) {
+    if let Some(loaded_addresses) = resolve_address_table_lookups(lookups, resolver) {
+      account_keys.extend(loaded_addresses);
+    }
+  }
+  let num_required_signatures = tx.message.header().num_required_signatures;
+  // No token balances are available, so the watchlist pre-check only has the account keys
+  if let Some(filter) = options.filter {
+    if !filter.matches_tx(&account_keys, num_required_signatures, &[]) {
+      return;
+    }
+  }
+  let signature = Signature::from(tx.signatures[0]);
+
+  let mut signers = HashSet::new();
+  for i in 0..num_required_signatures {
+    signers.insert(account_keys[i as usize]);
+  }
+
+  let mut decoded_tx = DecodedTx::default();
+  for (instr_index, raw_inst) in tx.message.instructions().iter().enumerate() {
+    // Unresolved lookup table accounts would make classifying index past the end of the account
+    // keys
+    if raw_inst.program_id_index as usize >= account_keys.len()
+      || raw_inst
+        .accounts
+        .iter()
+        .any(|account_index| *account_index as usize >= account_keys.len())
+    {
+      continue;
+    }
+    let instruction = Instruction {
+      tx_account_keys: &account_keys,
+      accounts: &raw_inst.accounts,
+      data: &raw_inst.data,
+      program_id_index: raw_inst.program_id_index,
+    };
+
+    let (instruction_type, swap_direction) = classify_pending_instruction(&instruction);
+
+    if instruction_type == InstructionType::PfBondingCurveCreate {
+      let creation = process_pf_bonding_curve_create_instruction(
+        &instruction,
+        received_time,
+        slot,
+        0,
+        instr_index as u8,
+        &signature,
+      )
+      .filter(|creation| {
+        options
+          .filter
+          .is_none_or(|filter| filter.matches_token_creation(creation))
+      });
+      if let Some(creation) = creation {
+        decoded_tx.token_creations.push(creation);
+      }
+    } else if let Some(pending_swap) = process_pending_swap_instruction(
+      &instruction,
+      instruction_type,
+      swap_direction,
+      slot,
+      instr_index as u8,
+      &signers,
+      &signature,
+    )
+    .filter(|pending_swap| {
+      options
+        .filter
+        .is_none_or(|filter| filter.matches_pending_swap(pending_swap))
+    }) {
+      decoded_tx.pending_swaps.push(pending_swap);
+    }
+  }
+  send_decoded_tx(decoded_tx, senders);
+}
@@ -0,0 +1,33 @@
+#[cfg(feature = "metrics")]
+use crate::metrics::decode_metrics::DECODE_METRICS;
+#[cfg(feature = "metrics")]
+use crate::metrics::decode_stage::DecodeStage;
+use crate::tx::decode_tx_source::decode_tx_source;
+use crate::tx::send_decoded_tx::send_decoded_tx;
+use crate::tx_sources::tx_source::TxSource;
+use crate::types::decode_options::DecodeOptions;
+use crate::types::decode_senders::DecodeSenders;
+#[cfg(feature = "metrics")]
+use std::time::Instant;
+
+/// Analyze a transaction from any `TxSource` and write everything `decode_tx_source` extracts from
+/// it to the channels in `senders`. This is the decoding core behind `analyze_tx`, use it directly
+/// for transaction sources that are not part of `TxFormat`, or use `decode_tx_source` to hold the
+/// output back before sending it.
+pub fn analyze_tx_source<S: TxSource + ?Sized>(
+  source: &S,
+  senders: &DecodeSenders,
+  options: &DecodeOptions,
+  block_time: u64,
+  slot: u64,
+  index: u64,
+) {
+  let Some(decoded_tx) = decode_tx_source(source, options, block_time, slot, index) else {
+    return;
+  };
+  #[cfg(feature = "metrics")]
+  let mut stage_start = Instant::now();
+  send_decoded_tx(decoded_tx, senders);
+  #[cfg(feature = "metrics")]
+  DECODE_METRICS.finish_stage(DecodeStage::Send, &mut stage_start);
+}
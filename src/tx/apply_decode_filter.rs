@@ -0,0 +1,35 @@
+use crate::types::decode_filter::DecodeFilter;
+use crate::types::decoded_tx::DecodedTx;
+
+/// Drop the output of a decoded transaction that doesn't pass `filter`, keeping `swaps` and
+/// `swap_details` parallel. Runs right after the instructions are decoded, before decimals, cost
+/// and wallet deltas are filled in, so no work is spent on swaps outside the watchlist and none of
+/// them reach the channels. Arbitrages and routes are kept if any of their swaps pass, so they are
+/// judged against the unfiltered swaps. `unrecognized_instructions` are kept, the transaction
+/// passed the pre-check.
+pub fn apply_decode_filter(filter: &DecodeFilter, decoded_tx: &mut DecodedTx) {
+  let swaps = &decoded_tx.swaps;
+  decoded_tx
+    .route_trades
+    .retain(|route_trade| filter.matches_route_trade(route_trade, swaps));
+  decoded_tx
+    .arbitrages
+    .retain(|arbitrage| filter.matches_arbitrage(arbitrage));
+  let keep: Vec<bool> = decoded_tx
+    .swaps
+    .iter()
+    .map(|swap_tx| filter.matches_swap(swap_tx))
+    .collect();
+  let mut keep_swaps = keep.iter();
+  decoded_tx.swaps.retain(|_| *keep_swaps.next().unwrap());
+  let mut keep_details = keep.iter();
+  decoded_tx
+    .swap_details
+    .retain(|_| *keep_details.next().unwrap());
+  decoded_tx
+    .token_creations
+    .retain(|token_creation| filter.matches_token_creation(token_creation));
+  decoded_tx
+    .perp_events
+    .retain(|perp_event| filter.matches_perp_event(perp_event));
+}
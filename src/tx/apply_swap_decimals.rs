@@ -0,0 +1,92 @@
+use crate::types::swap_details::SwapDetails;
+use solana_central::SwapTx;
+use solana_central::constants::LAMPORTS_PER_SOL;
+use solana_central::constants::TOKENS;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+
+/// Fill the token decimals and decimals adjusted prices of `swap_details` from the mint decimals
+/// found in the transaction's token balances. `swaps` and `swap_details` are parallel like in
+/// `DecodedTx`. WSOL falls back to 9 decimals since bonding curves hold native SOL and WSOL often
+/// has no token balance.
+pub fn apply_swap_decimals(
+  swaps: &[SwapTx],
+  swap_details: &mut [SwapDetails],
+  mint_decimals: &HashMap<Pubkey, u8>,
+) {
+  let decimals_of = |mint: &Pubkey| match mint_decimals.get(mint) {
+    Some(decimals) => Some(*decimals),
+    None if *mint == TOKENS.wsol => Some(9),
+    None => None,
+  };
+  for (swap, details) in swaps.iter().zip(swap_details.iter_mut()) {
+    details.token_a_decimals = decimals_of(&swap.token_a_address);
+    details.token_b_decimals = decimals_of(&swap.token_b_address);
+    if let (Some(token_a_decimals), Some(token_b_decimals)) =
+      (details.token_a_decimals, details.token_b_decimals)
+    {
+      // Raw prices are scaled by LAMPORTS_PER_SOL, a raw A per B ratio is scaled by 10^(a - b)
+      let decimals_diff = token_a_decimals as i32 - token_b_decimals as i32;
+      details.price_a_b_ui =
+        Some(swap.price_a_b_lp as f64 / LAMPORTS_PER_SOL as f64 / 10f64.powi(decimals_diff));
+      details.price_b_a_ui =
+        Some(swap.price_b_a_lp as f64 / LAMPORTS_PER_SOL as f64 * 10f64.powi(decimals_diff));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utilities::test_swap_tx_builder::TestSwapTxBuilder;
+  use solana_sdk::signature::Signature;
+
+  fn empty_details() -> SwapDetails {
+    SwapDetails {
+      signature: Signature::default(),
+      atomic_instruction_index: 0,
+      intent: None,
+      token_a_decimals: None,
+      token_b_decimals: None,
+      price_a_b_ui: None,
+      price_b_a_ui: None,
+      fees: None,
+      creator: None,
+      fee_recipient: None,
+      track_volume: None,
+      unknown_event_data: Vec::new(),
+    }
+  }
+
+  #[test]
+  fn adjusts_prices_for_decimals_with_wsol_fallback() {
+    let mint = Pubkey::new_unique();
+    // A whole token of `mint` (6 decimals) costs 0.5 SOL
+    let swap = TestSwapTxBuilder::default()
+      .tokens(TOKENS.wsol, mint)
+      .prices(500 * LAMPORTS_PER_SOL, 2_000_000)
+      .build();
+    let mut swap_details = [empty_details()];
+    apply_swap_decimals(&[swap], &mut swap_details, &HashMap::from([(mint, 6)]));
+    let details = &swap_details[0];
+    assert_eq!(details.token_a_decimals, Some(9));
+    assert_eq!(details.token_b_decimals, Some(6));
+    assert!((details.price_a_b_ui.unwrap() - 0.5).abs() < 1e-12);
+    assert!((details.price_b_a_ui.unwrap() - 2.0).abs() < 1e-12);
+  }
+
+  #[test]
+  fn unknown_decimals_leave_prices_unset() {
+    let mint = Pubkey::new_unique();
+    let swap = TestSwapTxBuilder::default()
+      .tokens(mint, Pubkey::new_unique())
+      .build();
+    let mut swap_details = [empty_details()];
+    apply_swap_decimals(&[swap], &mut swap_details, &HashMap::from([(mint, 6)]));
+    let details = &swap_details[0];
+    assert_eq!(details.token_a_decimals, Some(6));
+    assert_eq!(details.token_b_decimals, None);
+    assert_eq!(details.price_a_b_ui, None);
+    assert_eq!(details.price_b_a_ui, None);
+  }
+}
@@ -0,0 +1,39 @@
+use crate::constants::compute_budget::LAMPORTS_PER_SIGNATURE;
+use crate::instruction::compute_budget::decode_compute_budget::decode_compute_budget;
+use crate::tx_sources::tx_source::TxSource;
+use crate::types::tip::Tip;
+use crate::types::tx_cost::TxCost;
+use solana_central::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+/// Build the `TxCost` of a transaction from its ComputeBudget instructions and the meta fee and
+/// compute units consumed, plus the `tips` found in it. The priority fee is what the meta fee
+/// charged on top of the signature fee rather than recomputed from the compute budget, so it is
+/// exact even where the default compute unit limit isn't.
+pub fn compute_tx_cost<S: TxSource + ?Sized>(
+  source: &S,
+  top_level_instructions: &[Instruction],
+  account_keys: &[Pubkey],
+  block_time: u64,
+  slot: u64,
+  index: u64,
+  tips: Vec<Tip>,
+) -> TxCost {
+  let (compute_unit_limit, compute_unit_price) = decode_compute_budget(top_level_instructions);
+  let fee = source.fee();
+  let base_fee = LAMPORTS_PER_SIGNATURE * source.num_required_signatures() as u64;
+  TxCost {
+    signature: source.signature(),
+    block_time,
+    slot,
+    index,
+    fee_payer: account_keys[0],
+    fee,
+    base_fee,
+    priority_fee: fee.saturating_sub(base_fee),
+    compute_unit_limit,
+    compute_unit_price,
+    compute_units_consumed: source.compute_units_consumed(),
+    tips,
+  }
+}
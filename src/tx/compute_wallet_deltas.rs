@@ -0,0 +1,158 @@
+use crate::tx_sources::tx_source::TxSource;
+use crate::types::token_balance::TokenBalance;
+use crate::types::wallet_delta::WalletDelta;
+use solana_central::constants::TOKENS;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+
+/// Compute the `WalletDelta` of every signer of a transaction. Token accounts are attributed to a
+/// signer by the owner recorded in the token balances, so token changes are empty for metas from
+/// before owners were recorded.
+pub fn compute_wallet_deltas<S: TxSource + ?Sized>(
+  source: &S,
+  account_keys: &[Pubkey],
+  pre_token_balances: &[TokenBalance],
+  post_token_balances: &[TokenBalance],
+  block_time: u64,
+  slot: u64,
+  index: u64,
+) -> Vec<WalletDelta> {
+  let pre_balances = source.pre_balances();
+  let post_balances = source.post_balances();
+  let signature = source.signature();
+
+  let mut wallet_deltas: Vec<WalletDelta> = (0..source.num_required_signatures() as usize)
+    .map(|i| WalletDelta {
+      signature,
+      block_time,
+      slot,
+      index,
+      wallet: account_keys[i],
+      sol_change: post_balances[i] as i128 - pre_balances[i] as i128,
+      token_changes: HashMap::new(),
+    })
+    .collect();
+
+  // A token account missing from pre or post balances was created or closed in the transaction
+  let token_changes = pre_token_balances
+    .iter()
+    .map(|token_balance| (token_balance, -(token_balance.amount as i128)))
+    .chain(
+      post_token_balances
+        .iter()
+        .map(|token_balance| (token_balance, token_balance.amount as i128)),
+    );
+  for (token_balance, change) in token_changes {
+    let Some(owner) = token_balance.owner else {
+      continue;
+    };
+    let Some(wallet_delta) = wallet_deltas
+      .iter_mut()
+      .find(|wallet_delta| wallet_delta.wallet == owner)
+    else {
+      continue;
+    };
+    if token_balance.mint == TOKENS.wsol {
+      wallet_delta.sol_change += change;
+    } else {
+      *wallet_delta
+        .token_changes
+        .entry(token_balance.mint)
+        .or_insert(0) += change;
+    }
+  }
+  for wallet_delta in &mut wallet_deltas {
+    wallet_delta.token_changes.retain(|_, change| *change != 0);
+  }
+  wallet_deltas
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::tx_sources::test_tx_source::TestTxSource;
+
+  fn token_balance(account_index: u8, mint: Pubkey, amount: u64, owner: Pubkey) -> TokenBalance {
+    TokenBalance {
+      account_index,
+      mint,
+      amount,
+      decimals: 6,
+      owner: Some(owner),
+    }
+  }
+
+  #[test]
+  fn nets_native_and_wrapped_sol_and_token_changes() {
+    let wallet = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let unchanged_mint = Pubkey::new_unique();
+    let mut source = TestTxSource::new(vec![
+      wallet,
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+    ]);
+    source.pre_balances = vec![10_000_000, 0, 0, 0];
+    source.post_balances = vec![9_000_000, 0, 0, 0];
+    let pre_token_balances = vec![
+      token_balance(1, TOKENS.wsol, 2_000_000, wallet),
+      token_balance(3, unchanged_mint, 5, wallet),
+    ];
+    // The token account of `mint` was created in the transaction
+    let post_token_balances = vec![
+      token_balance(1, TOKENS.wsol, 500_000, wallet),
+      token_balance(2, mint, 700, wallet),
+      token_balance(3, unchanged_mint, 5, wallet),
+    ];
+    let wallet_deltas = compute_wallet_deltas(
+      &source,
+      &source.account_keys,
+      &pre_token_balances,
+      &post_token_balances,
+      1,
+      2,
+      3,
+    );
+    assert_eq!(wallet_deltas.len(), 1);
+    let wallet_delta = &wallet_deltas[0];
+    assert_eq!(wallet_delta.wallet, wallet);
+    assert_eq!(wallet_delta.signature, source.signature);
+    assert_eq!(wallet_delta.sol_change, -2_500_000);
+    assert_eq!(wallet_delta.token_changes.len(), 1);
+    assert_eq!(wallet_delta.token_changes[&mint], 700);
+  }
+
+  #[test]
+  fn token_accounts_are_attributed_by_owner() {
+    let signer_a = Pubkey::new_unique();
+    let signer_b = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let mut source = TestTxSource::new(vec![signer_a, signer_b, Pubkey::new_unique()]);
+    source.num_required_signatures = 2;
+    // Token account closed in the transaction
+    let pre_token_balances = vec![token_balance(2, mint, 100, signer_b)];
+    let mut post_token_balances = vec![token_balance(2, mint, 40, other)];
+    // Balances without an owner are ignored
+    post_token_balances.push(TokenBalance {
+      owner: None,
+      ..token_balance(2, mint, 1, other)
+    });
+    let wallet_deltas = compute_wallet_deltas(
+      &source,
+      &source.account_keys,
+      &pre_token_balances,
+      &post_token_balances,
+      1,
+      2,
+      3,
+    );
+    assert_eq!(wallet_deltas.len(), 2);
+    assert_eq!(wallet_deltas[0].wallet, signer_a);
+    assert!(wallet_deltas[0].token_changes.is_empty());
+    assert_eq!(wallet_deltas[1].wallet, signer_b);
+    assert_eq!(wallet_deltas[1].sol_change, 0);
+    assert_eq!(wallet_deltas[1].token_changes[&mint], -100);
+  }
+}
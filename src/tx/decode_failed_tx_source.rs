@@ -0,0 +1,283 @@
+use crate::instruction::classify_pending_instruction::classify_pending_instruction;
+use crate::instruction::classify_swap_failure::classify_swap_failure;
+use crate::instruction::process_pending_swap_instruction::process_pending_swap_instruction;
+use crate::tx::compute_tx_cost::compute_tx_cost;
+use crate::tx_sources::tx_source::TxSource;
+use crate::types::decode_options::DecodeOptions;
+use crate::types::decoded_tx::DecodedTx;
+use crate::types::failed_swap_attempt::FailedSwapAttempt;
+use crate::types::swap_failure_kind::SwapFailureKind;
+use bumpalo::Bump;
+use solana_central::Instruction;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Decode a failed transaction from any `TxSource` into `FailedSwapAttempt`s without sending
+/// them. A failed transaction has no swap outcome, so swaps are decoded from instruction arguments
+/// the same way as in `analyze_pending_tx`, from top level instructions and from the inner
+/// instructions that executed before the failure. Attempts outside the `filter` in `options` are
+/// dropped before they are built and the cost is only built if any attempt is left, the
+/// transaction pre-check of the filter is left to `decode_tx_source`. Returns `None` for
+/// transactions that didn't fail or whose account keys can't be reconstructed.
+pub fn decode_failed_tx_source<S: TxSource + ?Sized>(
+  source: &S,
+  options: &DecodeOptions,
+  block_time: u64,
+  slot: u64,
+  index: u64,
+) -> Option<DecodedTx> {
+  let error = source.err()?;
+  let account_keys = source.account_keys(options.address_lookup_table_resolver)?;
+  let arena = Bump::new();
+
+  let top_level_instructions = source.top_level_instructions(&account_keys);
+  let mut inner_instructions: HashMap<u8, Vec<Instruction>> = HashMap::new();
+  for inner_instruction in source.inner_instructions(&account_keys, &arena) {
+    inner_instructions
+      .entry(inner_instruction.top_level_index)
+      .or_insert(Vec::new())
+      .push(inner_instruction.instruction);
+  }
+
+  let signature = source.signature();
+  let mut signers = HashSet::new();
+  for i in 0..source.num_required_signatures() {
+    signers.insert(account_keys[i as usize]);
+  }
+
+  let (failed_instruction_index, custom_error_code) = match &error {
+    TransactionError::InstructionError(failed_index, instruction_error) => {
+      let custom_error_code = match instruction_error {
+        InstructionError::Custom(code) => Some(*code),
+        _ => None,
+      };
+      (Some(*failed_index), custom_error_code)
+    }
+    _ => (None, None),
+  };
+  // The program that returned the error is the innermost failing program in the logs, falling back
+  // to the program of the failed top level instruction
+  let failed_program = failed_instruction_index.and_then(|failed_index| {
+    source
+      .log_messages()
+      .iter()
+      .find_map(|log| {
+        let (program_id, _) = log.strip_prefix("Program ")?.split_once(" failed: ")?;
+        program_id.parse::<Pubkey>().ok()
+      })
+      .or_else(|| {
+        let instruction = top_level_instructions.get(failed_index as usize)?;
+        account_keys
+          .get(instruction.program_id_index as usize)
+          .copied()
+      })
+  });
+
+  let mut decoded_tx = DecodedTx::default();
+  for (instr_index, top_level_instruction) in top_level_instructions.iter().enumerate() {
+    let instr_index = instr_index as u8;
+    let inner = inner_instructions.get(&instr_index);
+    for instruction in std::iter::once(top_level_instruction).chain(inner.into_iter().flatten()) {
+      let (instruction_type, swap_direction) = classify_pending_instruction(instruction);
+      let Some(swap) = process_pending_swap_instruction(
+        instruction,
+        instruction_type,
+        swap_direction,
+        slot,
+        instr_index,
+        &signers,
+        &signature,
+      ) else {
+        continue;
+      };
+      if options
+        .filter
+        .is_some_and(|filter| !filter.matches_pending_swap(&swap))
+      {
+        continue;
+      }
+      // Errors not tied to an instruction (fees, rent) apply to every swap in the transaction
+      let failure_kind = if failed_instruction_index.is_none_or(|i| i == instr_index) {
+        classify_swap_failure(&swap.pool, &error, failed_program.as_ref())
+      } else {
+        SwapFailureKind::Other
+      };
+      decoded_tx.failed_swap_attempts.push(FailedSwapAttempt {
+        swap,
+        block_time,
+        index,
+        error: error.clone(),
+        failed_instruction_index,
+        custom_error_code,
+        failure_kind,
+      });
+    }
+  }
+  // Failed transactions still pay fees, but tip transfers are rolled back with everything else
+  if options.filter.is_none() || !decoded_tx.failed_swap_attempts.is_empty() {
+    decoded_tx.tx_cost = Some(compute_tx_cost(
+      source,
+      &top_level_instructions,
+      &account_keys,
+      block_time,
+      slot,
+      index,
+      Vec::new(),
+    ));
+  }
+  Some(decoded_tx)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::constants::token_program::TOKEN_PROGRAM_ID;
+  use crate::tx_sources::test_tx_source::TestInstruction;
+  use crate::tx_sources::test_tx_source::TestTxSource;
+  use solana_central::Pools;
+  use solana_central::SwapDirection;
+  use solana_central::constants::PUMP_CONSTANTS;
+
+  #[test]
+  fn pumpfun_event_cpi_before_the_failure_is_not_a_swap() {
+    let signer = Pubkey::new_unique();
+    let router = Pubkey::new_unique();
+    let other_program = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let bonding_curve = Pubkey::new_unique();
+    let mut source = TestTxSource::new(vec![
+      signer,
+      PUMP_CONSTANTS.bonding_curve_program,
+      PUMP_CONSTANTS.bonding_curve_event_authority,
+      mint,
+      bonding_curve,
+      router,
+      other_program,
+    ]);
+    let mut buy_data = PUMP_CONSTANTS.buy_instruction_discriminator.to_vec();
+    buy_data.extend_from_slice(&1_000_000u64.to_le_bytes());
+    buy_data.extend_from_slice(&2_000_000u64.to_le_bytes());
+    let mut event_data = PUMP_CONSTANTS.bonding_curve_event_discriminator.to_vec();
+    event_data.resize(266, 0);
+    // Router CPIs into a Pumpfun buy, which emits its event, then the next instruction fails
+    source.top_level_instructions = vec![
+      TestInstruction {
+        program_id_index: 5,
+        accounts: vec![0, 3, 4],
+        data: vec![1],
+      },
+      TestInstruction {
+        program_id_index: 6,
+        accounts: vec![0],
+        data: vec![0],
+      },
+    ];
+    source.inner_instructions = vec![
+      (
+        0,
+        TestInstruction {
+          program_id_index: 1,
+          accounts: vec![2, 2, 3, 4, 0],
+          data: buy_data,
+        },
+      ),
+      (
+        0,
+        TestInstruction {
+          program_id_index: 1,
+          accounts: vec![2],
+          data: event_data,
+        },
+      ),
+    ];
+    source.err = Some(TransactionError::InstructionError(
+      1,
+      InstructionError::Custom(1),
+    ));
+
+    let decoded_tx = decode_failed_tx_source(&source, &DecodeOptions::default(), 0, 1, 0).unwrap();
+    assert_eq!(decoded_tx.failed_swap_attempts.len(), 1);
+    let attempt = &decoded_tx.failed_swap_attempts[0];
+    assert!(matches!(attempt.swap.pool, Pools::PfBondingCurve));
+    assert!(attempt.swap.direction == Some(SwapDirection::BToA));
+    assert_eq!(attempt.swap.market_address, bonding_curve);
+    assert_eq!(attempt.failed_instruction_index, Some(1));
+    assert_eq!(attempt.failure_kind, SwapFailureKind::Other);
+  }
+
+  #[test]
+  fn swap_instruction_with_too_few_accounts_is_skipped() {
+    let signer = Pubkey::new_unique();
+    let mut source = TestTxSource::new(vec![signer, PUMP_CONSTANTS.pump_swap_program]);
+    let mut data = PUMP_CONSTANTS.buy_instruction_discriminator.to_vec();
+    data.extend_from_slice(&[0; 16]);
+    source.top_level_instructions = vec![TestInstruction {
+      program_id_index: 1,
+      accounts: vec![0],
+      data,
+    }];
+    source.err = Some(TransactionError::InstructionError(
+      0,
+      InstructionError::Custom(6004),
+    ));
+
+    let decoded_tx = decode_failed_tx_source(&source, &DecodeOptions::default(), 0, 1, 0).unwrap();
+    assert!(decoded_tx.failed_swap_attempts.is_empty());
+  }
+
+  #[test]
+  fn custom_code_1_is_insufficient_funds_only_if_a_token_program_failed() {
+    let signer = Pubkey::new_unique();
+    let mut source = TestTxSource::new(vec![
+      signer,
+      PUMP_CONSTANTS.bonding_curve_program,
+      PUMP_CONSTANTS.bonding_curve_event_authority,
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+    ]);
+    let mut data = PUMP_CONSTANTS.buy_instruction_discriminator.to_vec();
+    data.extend_from_slice(&1_000_000u64.to_le_bytes());
+    data.extend_from_slice(&2_000_000u64.to_le_bytes());
+    source.top_level_instructions = vec![TestInstruction {
+      program_id_index: 1,
+      accounts: vec![2, 2, 3, 4, 0],
+      data,
+    }];
+    source.err = Some(TransactionError::InstructionError(
+      0,
+      InstructionError::Custom(1),
+    ));
+
+    // Without logs the error is attributed to the Pumpfun program of the failed instruction
+    let decoded_tx = decode_failed_tx_source(&source, &DecodeOptions::default(), 0, 1, 0).unwrap();
+    assert_eq!(
+      decoded_tx.failed_swap_attempts[0].failure_kind,
+      SwapFailureKind::CustomProgramError
+    );
+
+    source.log_messages = vec![
+      format!(
+        "Program {} invoke [1]",
+        PUMP_CONSTANTS.bonding_curve_program
+      ),
+      "Program log: transfer failed: not a program".to_string(),
+      format!("Program {} invoke [2]", TOKEN_PROGRAM_ID),
+      format!(
+        "Program {} failed: custom program error: 0x1",
+        TOKEN_PROGRAM_ID
+      ),
+      format!(
+        "Program {} failed: custom program error: 0x1",
+        PUMP_CONSTANTS.bonding_curve_program
+      ),
+    ];
+    let decoded_tx = decode_failed_tx_source(&source, &DecodeOptions::default(), 0, 1, 0).unwrap();
+    assert_eq!(
+      decoded_tx.failed_swap_attempts[0].failure_kind,
+      SwapFailureKind::InsufficientFunds
+    );
+  }
+}
@@ -0,0 +1,355 @@
+use crate::instruction::token::decode_token_transfer::decode_token_transfer;
+#[cfg(feature = "metrics")]
+use crate::metrics::decode_metrics::DECODE_METRICS;
+#[cfg(feature = "metrics")]
+use crate::metrics::decode_stage::DecodeStage;
+use crate::tx::apply_decode_filter::apply_decode_filter;
+use crate::tx::apply_swap_decimals::apply_swap_decimals;
+use crate::tx::compute_tx_cost::compute_tx_cost;
+use crate::tx::compute_wallet_deltas::compute_wallet_deltas;
+use crate::tx::decode_failed_tx_source::decode_failed_tx_source;
+use crate::tx::detect_arbitrage::detect_arbitrage;
+use crate::tx::find_tips::find_tips;
+use crate::tx::parse_program_logs::parse_program_logs;
+use crate::tx::process_log_events::process_log_events;
+use crate::tx::reconcile_running_token_balances::reconcile_running_token_balances;
+use crate::tx::top_level_instructions_loop::top_level_instructions_loop;
+use crate::tx_sources::tx_source::TxSource;
+use crate::types::decode_options::DecodeOptions;
+use crate::types::decoded_tx::DecodedTx;
+use crate::types::log_truncation::LogTruncation;
+use crate::types::running_token_balances::RunningTokenBalances;
+use bumpalo::Bump;
+use solana_central::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::collections::HashSet;
+#[cfg(feature = "metrics")]
+use std::time::Instant;
+
+/// Decode a transaction from any `TxSource` into swaps, token creations, arbitrage cycles, signer
+/// balance changes and its cost without sending them. With `reconcile_token_balances` set in
+/// `options`, the running token balances are also checked against the post token balances, and
+/// with `decode_log_events` set events from program logs are decoded as a fallback. With a `filter`
+/// set, transactions that fail its pre-check are skipped before decoding, failed ones included,
+/// output outside the watchlist is dropped by `apply_decode_filter` and cost and wallet deltas are
+/// only built if any output is left. Failed transactions are skipped unless `decode_failed_txs` is
+/// set in `options`, in which case they are decoded by `decode_failed_tx_source`. Returns `None`
+/// for skipped transactions, including v0 transactions whose account keys can't be reconstructed.
+pub fn decode_tx_source<S: TxSource + ?Sized>(
+  source: &S,
+  options: &DecodeOptions,
+  block_time: u64,
+  slot: u64,
+  index: u64,
+) -> Option<DecodedTx> {
+  #[cfg(feature = "metrics")]
+  DECODE_METRICS.record_tx_seen();
+  #[cfg(feature = "metrics")]
+  let mut stage_start = Instant::now();
+  // Failed transactions that aren't decoded produce nothing, so they aren't worth pre-checking
+  if source.is_err() && !options.decode_failed_txs {
+    #[cfg(feature = "metrics")]
+    DECODE_METRICS.record_tx_failed();
+    return None;
+  }
+  let Some(account_keys) = source.account_keys(options.address_lookup_table_resolver) else {
+    #[cfg(feature = "metrics")]
+    DECODE_METRICS.record_tx_skipped();
+    return None;
+  };
+  let pre_token_balances = source.pre_token_balances();
+  let post_token_balances = source.post_token_balances();
+  // Transactions that can't produce anything on the watchlist are skipped before decoding
+  if let Some(filter) = options.filter {
+    let token_balance_mints: Vec<Pubkey> = pre_token_balances
+      .iter()
+      .chain(&post_token_balances)
+      .map(|token_balance| token_balance.mint)
+      .collect();
+    if !filter.matches_tx(
+      &account_keys,
+      source.num_required_signatures(),
+      &token_balance_mints,
+    ) {
+      #[cfg(feature = "metrics")]
+      DECODE_METRICS.record_tx_skipped();
+      return None;
+    }
+  }
+  if source.is_err() {
+    #[cfg(feature = "metrics")]
+    DECODE_METRICS.record_tx_failed();
+    let decoded_tx = decode_failed_tx_source(source, options, block_time, slot, index);
+    #[cfg(feature = "metrics")]
+    if let Some(decoded_tx) = &decoded_tx {
+      DECODE_METRICS.record_decoded_tx(decoded_tx);
+    }
+    return decoded_tx;
+  }
+  // Backs instruction data that has to be decoded before it can be referenced (JSON RPC)
+  let arena = Bump::new();
+
+  let top_level_instructions = source.top_level_instructions(&account_keys);
+  // Key is the top level instruction index, value is the list of inner instructions yielded by that top level instruction
+  let mut inner_instructions: HashMap<u8, Vec<Instruction>> = HashMap::new();
+  for inner_instruction in source.inner_instructions(&account_keys, &arena) {
+    inner_instructions
+      .entry(inner_instruction.top_level_index)
+      .or_insert(Vec::new())
+      .push(inner_instruction.instruction);
+  }
+
+  // Maps a token account address index to the token address (mint) that that token account is for
+  let mut ta_mint: HashMap<u8, Pubkey> = HashMap::new();
+  // Maps a token account address index to the token balance of that token account
+  let mut running_token_balances = RunningTokenBalances::new();
+  // Maps a token address (mint) to its decimals
+  let mut mint_decimals: HashMap<Pubkey, u8> = HashMap::new();
+  for pre_token_balance in &pre_token_balances {
+    ta_mint.insert(pre_token_balance.account_index, pre_token_balance.mint);
+    mint_decimals.insert(pre_token_balance.mint, pre_token_balance.decimals);
+    running_token_balances.insert(pre_token_balance.account_index, pre_token_balance.amount);
+  }
+  for post_token_balance in &post_token_balances {
+    ta_mint.insert(post_token_balance.account_index, post_token_balance.mint);
+    mint_decimals.insert(post_token_balance.mint, post_token_balance.decimals);
+    running_token_balances.insert_post(post_token_balance.account_index, post_token_balance.amount);
+  }
+  // Token-2022 transfer fees are resolved from post balances for accounts moved by one transfer
+  for instruction in top_level_instructions
+    .iter()
+    .chain(inner_instructions.values().flatten())
+  {
+    if let Some(transfer) = decode_token_transfer(instruction) {
+      running_token_balances.count_transfer(&transfer);
+    }
+  }
+
+  let signature = source.signature();
+  let mut signers = HashSet::new();
+  for i in 0..source.num_required_signatures() {
+    signers.insert(account_keys[i as usize]);
+  }
+
+  let mut decoded_tx = DecodedTx::default();
+  #[cfg(feature = "metrics")]
+  DECODE_METRICS.finish_stage(DecodeStage::Prepare, &mut stage_start);
+  top_level_instructions_loop(
+    &top_level_instructions,
+    &inner_instructions,
+    &account_keys,
+    &ta_mint,
+    &mut running_token_balances,
+    &mut decoded_tx,
+    block_time,
+    slot,
+    index,
+    &signers,
+    &signature,
+  );
+  #[cfg(feature = "metrics")]
+  DECODE_METRICS.finish_stage(DecodeStage::Instructions, &mut stage_start);
+  if options.decode_log_events {
+    let program_logs = parse_program_logs(&source.log_messages());
+    process_log_events(
+      &program_logs.events,
+      &top_level_instructions,
+      &inner_instructions,
+      &account_keys,
+      &mut decoded_tx,
+      block_time,
+      slot,
+      index,
+      &signers,
+      &signature,
+    );
+    if program_logs.truncated {
+      decoded_tx.log_truncations.push(LogTruncation {
+        signature,
+        block_time,
+        slot,
+        index,
+        log_events_parsed: program_logs.events.len(),
+      });
+    }
+    #[cfg(feature = "metrics")]
+    DECODE_METRICS.finish_stage(DecodeStage::LogEvents, &mut stage_start);
+  }
+  decoded_tx.arbitrages = detect_arbitrage(&decoded_tx.swaps);
+  if let Some(filter) = options.filter {
+    apply_decode_filter(filter, &mut decoded_tx);
+  }
+  apply_swap_decimals(
+    &decoded_tx.swaps,
+    &mut decoded_tx.swap_details,
+    &mint_decimals,
+  );
+  // A filtered transaction without output left on the watchlist has no use for its cost and
+  // wallet deltas
+  let has_output = !decoded_tx.swaps.is_empty()
+    || !decoded_tx.token_creations.is_empty()
+    || !decoded_tx.route_trades.is_empty()
+    || !decoded_tx.perp_events.is_empty();
+  if options.filter.is_none() || has_output {
+    decoded_tx.tx_cost = Some(compute_tx_cost(
+      source,
+      &top_level_instructions,
+      &account_keys,
+      block_time,
+      slot,
+      index,
+      find_tips(
+        &top_level_instructions,
+        &inner_instructions,
+        options.tip_accounts,
+      ),
+    ));
+    decoded_tx.wallet_deltas = compute_wallet_deltas(
+      source,
+      &account_keys,
+      &pre_token_balances,
+      &post_token_balances,
+      block_time,
+      slot,
+      index,
+    );
+  }
+  if options.reconcile_token_balances {
+    decoded_tx.balance_mismatches = reconcile_running_token_balances(
+      &running_token_balances,
+      &ta_mint,
+      &account_keys,
+      &signature,
+      block_time,
+      slot,
+      index,
+    );
+  }
+  #[cfg(feature = "metrics")]
+  {
+    DECODE_METRICS.finish_stage(DecodeStage::PostProcess, &mut stage_start);
+    DECODE_METRICS.record_decoded_tx(&decoded_tx);
+  }
+  Some(decoded_tx)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::tx_sources::test_tx_source::TestInstruction;
+  use crate::tx_sources::test_tx_source::TestTxSource;
+  use crate::types::decode_filter::DecodeFilter;
+  use solana_central::constants::PUMP_CONSTANTS;
+  use solana_central::constants::RAYDIUM_CONSTANTS;
+  use solana_sdk::transaction::TransactionError;
+
+  /// Signer, Raydium CPMM, Pumpswap and a router program
+  fn test_source() -> TestTxSource {
+    TestTxSource::new(vec![
+      Pubkey::new_unique(),
+      RAYDIUM_CONSTANTS.cpmm_program,
+      PUMP_CONSTANTS.pump_swap_program,
+      Pubkey::new_unique(),
+    ])
+  }
+
+  fn cpmm_swap() -> TestInstruction {
+    let mut data = RAYDIUM_CONSTANTS.cpmm_swap_discriminators[0].to_vec();
+    data.resize(24, 0);
+    TestInstruction {
+      program_id_index: 1,
+      accounts: vec![0; 13],
+      data,
+    }
+  }
+
+  #[test]
+  fn top_level_swap_without_inner_instructions_is_unrecognized() {
+    let mut source = test_source();
+    source.top_level_instructions = vec![cpmm_swap()];
+    let decoded_tx = decode_tx_source(&source, &DecodeOptions::default(), 0, 1, 0).unwrap();
+    assert!(decoded_tx.swaps.is_empty());
+    assert_eq!(decoded_tx.unrecognized_instructions.len(), 1);
+    let unrecognized = &decoded_tx.unrecognized_instructions[0];
+    assert_eq!(unrecognized.program_id, RAYDIUM_CONSTANTS.cpmm_program);
+    assert_eq!(unrecognized.atomic_instruction_index, 0);
+  }
+
+  #[test]
+  fn inner_swap_without_its_transfers_is_unrecognized() {
+    let mut source = test_source();
+    source.top_level_instructions = vec![TestInstruction {
+      program_id_index: 3,
+      accounts: vec![0],
+      data: vec![1],
+    }];
+    source.inner_instructions = vec![(0, cpmm_swap())];
+    let decoded_tx = decode_tx_source(&source, &DecodeOptions::default(), 0, 1, 0).unwrap();
+    assert!(decoded_tx.swaps.is_empty());
+    assert_eq!(decoded_tx.unrecognized_instructions.len(), 1);
+    assert_eq!(
+      decoded_tx.unrecognized_instructions[0].atomic_instruction_index,
+      1
+    );
+  }
+
+  #[test]
+  fn unclassified_instruction_of_supported_program_is_unrecognized() {
+    let mut source = test_source();
+    source.top_level_instructions = vec![TestInstruction {
+      program_id_index: 2,
+      accounts: vec![0],
+      data: vec![7; 8],
+    }];
+    let decoded_tx = decode_tx_source(&source, &DecodeOptions::default(), 0, 1, 0).unwrap();
+    assert_eq!(decoded_tx.unrecognized_instructions.len(), 1);
+    assert_eq!(
+      decoded_tx.unrecognized_instructions[0].program_id,
+      PUMP_CONSTANTS.pump_swap_program
+    );
+    assert_eq!(
+      decoded_tx.unrecognized_instructions[0].discriminator,
+      vec![7; 8]
+    );
+  }
+
+  #[test]
+  fn failed_tx_is_pre_checked_by_the_filter() {
+    let mut source = test_source();
+    source.err = Some(TransactionError::AccountInUse);
+    let mut filter = DecodeFilter::default();
+    let options = DecodeOptions {
+      decode_failed_txs: true,
+      filter: Some(&filter),
+      ..Default::default()
+    };
+    assert!(decode_tx_source(&source, &options, 0, 1, 0).is_some());
+
+    filter
+      .exclude_programs
+      .insert(PUMP_CONSTANTS.pump_swap_program);
+    let options = DecodeOptions {
+      decode_failed_txs: true,
+      filter: Some(&filter),
+      ..Default::default()
+    };
+    assert!(decode_tx_source(&source, &options, 0, 1, 0).is_none());
+  }
+
+  #[test]
+  fn cost_is_only_built_for_filtered_txs_with_output() {
+    let source = test_source();
+    let decoded_tx = decode_tx_source(&source, &DecodeOptions::default(), 0, 1, 0).unwrap();
+    assert!(decoded_tx.tx_cost.is_some());
+
+    let filter = DecodeFilter::default();
+    let options = DecodeOptions {
+      filter: Some(&filter),
+      ..Default::default()
+    };
+    let decoded_tx = decode_tx_source(&source, &options, 0, 1, 0).unwrap();
+    assert!(decoded_tx.tx_cost.is_none());
+    assert!(decoded_tx.wallet_deltas.is_empty());
+  }
+}
@@ -0,0 +1,173 @@
+use crate::types::arbitrage_event::ArbitrageEvent;
+use crate::utilities::swap_tokens::swap_tokens;
+use solana_central::SwapTx;
+
+/// Find atomic arbitrage cycles in the swaps of a single transaction, ordered by atomic
+/// instruction index. Consecutive swaps between the same two tokens are one leg split across pools.
+/// Legs are chained while each one spends the token the previous one received, and a chain is a
+/// cycle once it receives the token it started with.
+pub fn detect_arbitrage(swaps: &[SwapTx]) -> Vec<ArbitrageEvent> {
+  // Range of swaps of each leg
+  let mut legs: Vec<(usize, usize)> = Vec::new();
+  for (i, swap) in swaps.iter().enumerate() {
+    match legs.last_mut() {
+      Some((leg_start, leg_end)) if swap_tokens(&swaps[*leg_start]) == swap_tokens(swap) => {
+        *leg_end = i + 1;
+      }
+      _ => legs.push((i, i + 1)),
+    }
+  }
+
+  let mut arbitrages = Vec::new();
+  let mut start = 0;
+  while start < legs.len() {
+    let (start_token, mut current_token) = swap_tokens(&swaps[legs[start].0]);
+    let mut end = None;
+    for (i, leg) in legs.iter().enumerate().skip(start + 1) {
+      let (token_in, token_out) = swap_tokens(&swaps[leg.0]);
+      if token_in != current_token {
+        break;
+      }
+      if token_out == start_token {
+        end = Some(i);
+        break;
+      }
+      current_token = token_out;
+    }
+    let Some(end) = end else {
+      start += 1;
+      continue;
+    };
+    let first_leg = &swaps[legs[start].0..legs[start].1];
+    let last_leg = &swaps[legs[end].0..legs[end].1];
+    let amount_in = first_leg
+      .iter()
+      .fold(0u64, |sum, swap| sum.saturating_add(swap.swapped_amount_in));
+    let amount_out = last_leg.iter().fold(0u64, |sum, swap| {
+      sum.saturating_add(swap.swapped_amount_received)
+    });
+    let first = &first_leg[0];
+    arbitrages.push(ArbitrageEvent {
+      signature: first.signature,
+      block_time: first.block_time,
+      slot: first.slot,
+      index: first.index,
+      signers: first.signers.clone(),
+      route: swaps[legs[start].0..legs[end].1].to_vec(),
+      start_token,
+      amount_in,
+      amount_out,
+      profit: amount_out as i128 - amount_in as i128,
+    });
+    start = end + 1;
+  }
+  arbitrages
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utilities::test_swap_tx_builder::TestSwapTxBuilder;
+  use solana_sdk::pubkey::Pubkey;
+
+  #[test]
+  fn detects_a_closed_cycle() {
+    let (sol, usdc, bonk) = (
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+    );
+    let swaps = [
+      TestSwapTxBuilder::default()
+        .tokens(sol, usdc)
+        .amounts(1_000, 150)
+        .build(),
+      TestSwapTxBuilder::default()
+        .tokens(usdc, bonk)
+        .amounts(150, 9_000)
+        .build(),
+      TestSwapTxBuilder::default()
+        .tokens(bonk, sol)
+        .amounts(9_000, 1_020)
+        .build(),
+    ];
+    let arbitrages = detect_arbitrage(&swaps);
+    assert_eq!(arbitrages.len(), 1);
+    assert_eq!(arbitrages[0].route.len(), 3);
+    assert_eq!(arbitrages[0].start_token, sol);
+    assert_eq!(arbitrages[0].amount_in, 1_000);
+    assert_eq!(arbitrages[0].amount_out, 1_020);
+    assert_eq!(arbitrages[0].profit, 20);
+  }
+
+  #[test]
+  fn broken_chain_is_not_a_cycle() {
+    let (sol, usdc, bonk) = (
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+    );
+    let swaps = [
+      TestSwapTxBuilder::default()
+        .tokens(sol, usdc)
+        .amounts(1_000, 150)
+        .build(),
+      TestSwapTxBuilder::default()
+        .tokens(bonk, sol)
+        .amounts(9_000, 1_020)
+        .build(),
+    ];
+    assert!(detect_arbitrage(&swaps).is_empty());
+  }
+
+  #[test]
+  fn cycle_after_an_unrelated_swap_is_found() {
+    let (sol, usdc, bonk) = (
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+    );
+    let swaps = [
+      TestSwapTxBuilder::default()
+        .tokens(bonk, usdc)
+        .amounts(10, 1)
+        .build(),
+      TestSwapTxBuilder::default()
+        .tokens(sol, usdc)
+        .amounts(1_000, 150)
+        .build(),
+      TestSwapTxBuilder::default()
+        .tokens(usdc, sol)
+        .amounts(150, 990)
+        .build(),
+    ];
+    let arbitrages = detect_arbitrage(&swaps);
+    assert_eq!(arbitrages.len(), 1);
+    assert_eq!(arbitrages[0].profit, -10);
+  }
+
+  #[test]
+  fn split_legs_are_one_leg() {
+    let (sol, bonk) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let swaps = [
+      TestSwapTxBuilder::default()
+        .tokens(sol, bonk)
+        .amounts(500, 5_000)
+        .build(),
+      TestSwapTxBuilder::default()
+        .tokens(sol, bonk)
+        .amounts(500, 4_000)
+        .build(),
+      TestSwapTxBuilder::default()
+        .tokens(bonk, sol)
+        .amounts(9_000, 1_020)
+        .build(),
+    ];
+    let arbitrages = detect_arbitrage(&swaps);
+    assert_eq!(arbitrages.len(), 1);
+    assert_eq!(arbitrages[0].route.len(), 3);
+    assert_eq!(arbitrages[0].amount_in, 1_000);
+    assert_eq!(arbitrages[0].amount_out, 1_020);
+    assert_eq!(arbitrages[0].profit, 20);
+  }
+}
@@ -0,0 +1,111 @@
+use crate::constants::tip_accounts::JITO_TIP_ACCOUNTS;
+use crate::instruction::system::decode_system_transfer::decode_system_transfer;
+use crate::types::tip::Tip;
+use solana_central::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Find SOL transfers to tip accounts in a transaction, both top level and yielded by top level
+/// instructions since bots often tip from their own program. The Jito tip accounts always count as
+/// tip destinations, `tip_accounts` adds the accounts of other providers.
+pub fn find_tips(
+  top_level_instructions: &[Instruction],
+  inner_instructions: &HashMap<u8, Vec<Instruction>>,
+  tip_accounts: Option<&HashSet<Pubkey>>,
+) -> Vec<Tip> {
+  let is_tip_account = |account: &Pubkey| {
+    JITO_TIP_ACCOUNTS.contains(account)
+      || tip_accounts.is_some_and(|tip_accounts| tip_accounts.contains(account))
+  };
+  let mut tips = Vec::new();
+  for (instr_index, top_level_instruction) in top_level_instructions.iter().enumerate() {
+    let instr_index = instr_index as u8;
+    let inner = inner_instructions.get(&instr_index);
+    for instruction in std::iter::once(top_level_instruction).chain(inner.into_iter().flatten()) {
+      let Some((payer, destination, lamports)) = decode_system_transfer(instruction) else {
+        continue;
+      };
+      if is_tip_account(&destination) {
+        tips.push(Tip {
+          payer,
+          destination,
+          lamports,
+          instruction_index: instr_index,
+        });
+      }
+    }
+  }
+  tips
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::constants::system_program::SYSTEM_PROGRAM_ID;
+  use crate::constants::system_program::SYSTEM_TRANSFER_TAG;
+
+  fn transfer_data(lamports: u64) -> Vec<u8> {
+    let mut data = SYSTEM_TRANSFER_TAG.to_le_bytes().to_vec();
+    data.extend_from_slice(&lamports.to_le_bytes());
+    data
+  }
+
+  #[test]
+  fn other_providers_are_detected_next_to_jito() {
+    let payer = Pubkey::new_unique();
+    let other_tip_account = Pubkey::new_unique();
+    let account_keys = vec![
+      payer,
+      SYSTEM_PROGRAM_ID,
+      JITO_TIP_ACCOUNTS[0],
+      other_tip_account,
+      Pubkey::new_unique(),
+    ];
+    let jito_data = transfer_data(1_000);
+    let other_data = transfer_data(2_000);
+    let plain_data = transfer_data(3_000);
+    let (jito_accounts, plain_accounts, other_accounts) =
+      (vec![0u8, 2], vec![0u8, 4], vec![0u8, 3]);
+    let top_level_instructions = [
+      Instruction {
+        tx_account_keys: &account_keys,
+        accounts: &jito_accounts,
+        data: &jito_data,
+        program_id_index: 1,
+      },
+      Instruction {
+        tx_account_keys: &account_keys,
+        accounts: &plain_accounts,
+        data: &plain_data,
+        program_id_index: 1,
+      },
+    ];
+    let mut inner_instructions = HashMap::new();
+    inner_instructions.insert(
+      1,
+      vec![Instruction {
+        tx_account_keys: &account_keys,
+        accounts: &other_accounts,
+        data: &other_data,
+        program_id_index: 1,
+      }],
+    );
+    let other_tip_accounts = HashSet::from([other_tip_account]);
+
+    let jito_only = find_tips(&top_level_instructions, &inner_instructions, None);
+    assert_eq!(jito_only.len(), 1);
+    assert_eq!(jito_only[0].lamports, 1_000);
+
+    let tips = find_tips(
+      &top_level_instructions,
+      &inner_instructions,
+      Some(&other_tip_accounts),
+    );
+    assert_eq!(tips.len(), 2);
+    assert_eq!(tips[0].destination, JITO_TIP_ACCOUNTS[0]);
+    assert_eq!(tips[1].destination, other_tip_account);
+    assert_eq!(tips[1].instruction_index, 1);
+    assert_eq!(tips[1].payer, payer);
+  }
+}
@@ -1,31 +1,35 @@
 use crate::instruction::classify_instruction::classify_instruction;
+use crate::instruction::is_unhandled_supported_instruction::is_unhandled_supported_instruction;
+use crate::instruction::jupiter_perps::process_jupiter_perps_event_instruction::process_jupiter_perps_event_instruction;
+use crate::instruction::pumpfun::find_pf_bonding_curve_swap_instruction::find_pf_bonding_curve_swap_instruction;
 use crate::instruction::pumpfun::process_pf_bonding_curve_create_instruction::process_pf_bonding_curve_create_instruction;
 use crate::instruction::pumpfun::process_pumpfun_event_instruction::process_pumpfun_event_instruction;
 use crate::instruction::pumpswap::process_pumpswap_swap_instruction::process_pumpswap_swap_instruction;
 use crate::instruction::raydium::process_raydium_ammv4_swap_instruction::process_raydium_ammv4_swap_instruction;
 use crate::instruction::raydium::process_raydium_cpmm_swap_instruction::process_raydium_cpmm_swap_instruction;
 use crate::instruction::raydium::process_raydium_launchpad_swap_instruction::process_raydium_launchpad_swap_instruction;
+use crate::types::decoded_tx::DecodedTx;
 use crate::types::instruction_type::InstructionType;
+use crate::types::running_token_balances::RunningTokenBalances;
 use solana_central::Instruction;
-use solana_central::SwapTx;
-use solana_central::TokenCreation;
 use solana_central::constants::PUMP_CONSTANTS;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use std::collections::HashMap;
 use std::collections::HashSet;
-use tokio::sync::broadcast::Sender;
 
-/// Handles inner instructions that result from program invocations. Processes swap instructions
-/// and token creation events from various protocols, sending results to broadcast channels just
-/// like `top_level_instructions_loop` does.
+/// Handles inner instructions that result from program invocations. Processes swap instructions,
+/// token creation events and perps events from various protocols, pushing results into `decoded_tx`
+/// just like `top_level_instructions_loop` does. Swaps that can't be decoded and other instructions of
+/// supported programs are pushed as `UnrecognizedInstruction`s.
 pub fn inner_instructions_loop(
   inner_instructions: &Vec<Instruction>,
+  // The top level instruction these inner instructions were yielded by
+  top_level_instruction: &Instruction,
   account_keys: &Vec<Pubkey>,
   ta_mint: &HashMap<u8, Pubkey>,
-  running_token_balances: &mut HashMap<u8, u64>,
-  swap_tx_sender: &Sender<SwapTx>,
-  token_create_sender: &Sender<TokenCreation>,
+  running_token_balances: &mut RunningTokenBalances,
+  decoded_tx: &mut DecodedTx,
   block_time: u64,
   slot: u64,
   index: u64,
@@ -41,23 +45,34 @@ pub fn inner_instructions_loop(
     let (instruction_type, swap_direction) = classify_instruction(&instruction);
     if instruction_type == InstructionType::RaydiumLaunchpadSwap {
       // Event instruction is first instruction after the swap
-      let event = &inner_instructions[instr_index + 1];
-      let swap_tx = process_raydium_launchpad_swap_instruction(
+      let swap = inner_instructions.get(instr_index + 1).and_then(|event| {
+        process_raydium_launchpad_swap_instruction(
+          instruction,
+          event,
+          swap_direction,
+          block_time,
+          slot,
+          index,
+          *atomic_instruction_index,
+          signers,
+          signature,
+        )
+      });
+      decoded_tx.push_swap_or_unrecognized(
+        swap,
         instruction,
-        event,
-        swap_direction,
         block_time,
         slot,
         index,
         *atomic_instruction_index,
-        signers,
         signature,
       );
-      let _ = swap_tx_sender.send(swap_tx);
     } else if instruction_type == InstructionType::RaydiumCpmmSwap {
       // The transfers are the two instructions immediately after the swap
-      let transfers = &inner_instructions[instr_index + 1..instr_index + 3];
-      let swap_tx = process_raydium_cpmm_swap_instruction(
+      let transfers = inner_instructions
+        .get(instr_index + 1..instr_index + 3)
+        .unwrap_or(&[]);
+      let swap = process_raydium_cpmm_swap_instruction(
         instruction,
         transfers,
         running_token_balances,
@@ -68,11 +83,21 @@ pub fn inner_instructions_loop(
         signers,
         signature,
       );
-      let _ = swap_tx_sender.send(swap_tx);
+      decoded_tx.push_swap_or_unrecognized(
+        swap,
+        instruction,
+        block_time,
+        slot,
+        index,
+        *atomic_instruction_index,
+        signature,
+      );
     } else if instruction_type == InstructionType::RaydiumAmmV4Swap {
       // The transfers are the two instructions immediately after the swap
-      let transfers = &inner_instructions[instr_index + 1..instr_index + 3];
-      let swap_tx = process_raydium_ammv4_swap_instruction(
+      let transfers = inner_instructions
+        .get(instr_index + 1..instr_index + 3)
+        .unwrap_or(&[]);
+      let swap = process_raydium_ammv4_swap_instruction(
         instruction,
         transfers,
         ta_mint,
@@ -84,21 +109,28 @@ pub fn inner_instructions_loop(
         signers,
         signature,
       );
-      let _ = swap_tx_sender.send(swap_tx);
+      decoded_tx.push_swap_or_unrecognized(
+        swap,
+        instruction,
+        block_time,
+        slot,
+        index,
+        *atomic_instruction_index,
+        signature,
+      );
     } else if instruction_type == InstructionType::PumpswapSwap {
       // To find this event, look from the instructions following the swap until we find one that is for the pumpswap program
-      let mut event = None;
-      for i in instr_index + 1..inner_instructions.len() {
-        if account_keys[inner_instructions[i].program_id_index as usize]
-          == PUMP_CONSTANTS.pump_swap_program
-        {
-          event = Some(&inner_instructions[i]);
-          break;
-        }
-      }
-      let swap_tx = process_pumpswap_swap_instruction(
+      let event = inner_instructions[instr_index + 1..]
+        .iter()
+        .find(|inner_instruction| {
+          account_keys.get(inner_instruction.program_id_index as usize)
+            == Some(&PUMP_CONSTANTS.pump_swap_program)
+        });
+      // Without its event the swap can't be decoded, report the swap instruction itself
+      let swap = event.and_then(|event| {
+        process_pumpswap_swap_instruction(
           instruction,
-          event.expect(&format!("inner_instructions_loop: Unable to find pumpswap event following inner instruction swap, signature: {}", signature.to_string())),
+          event,
           swap_direction,
           block_time,
           slot,
@@ -106,11 +138,26 @@ pub fn inner_instructions_loop(
           *atomic_instruction_index,
           signers,
           signature,
-        );
-      let _ = swap_tx_sender.send(swap_tx);
+        )
+      });
+      decoded_tx.push_swap_or_unrecognized(
+        swap,
+        event.unwrap_or(instruction),
+        block_time,
+        slot,
+        index,
+        *atomic_instruction_index,
+        signature,
+      );
     } else if instruction_type == InstructionType::PfBondingCurveSwap {
-      let swap_tx = process_pumpfun_event_instruction(
+      let swap_instruction = find_pf_bonding_curve_swap_instruction(
+        inner_instructions,
+        instr_index,
+        top_level_instruction,
+      );
+      let swap = process_pumpfun_event_instruction(
         instruction,
+        swap_instruction,
         block_time,
         slot,
         index,
@@ -118,7 +165,15 @@ pub fn inner_instructions_loop(
         signers,
         signature,
       );
-      let _ = swap_tx_sender.send(swap_tx);
+      decoded_tx.push_swap_or_unrecognized(
+        swap,
+        instruction,
+        block_time,
+        slot,
+        index,
+        *atomic_instruction_index,
+        signature,
+      );
     } else if instruction_type == InstructionType::PfBondingCurveCreate {
       let creation = process_pf_bonding_curve_create_instruction(
         instruction,
@@ -128,10 +183,51 @@ pub fn inner_instructions_loop(
         *atomic_instruction_index,
         signature,
       );
-      let _ = token_create_sender.send(creation);
+      if let Some(creation) = creation {
+        decoded_tx.token_creations.push(creation);
+      } else {
+        decoded_tx.push_unrecognized(
+          instruction,
+          block_time,
+          slot,
+          index,
+          *atomic_instruction_index,
+          signature,
+        );
+      }
+    } else if instruction_type == InstructionType::JupiterPerpsEvent {
+      let perp_event = process_jupiter_perps_event_instruction(
+        instruction,
+        block_time,
+        slot,
+        index,
+        *atomic_instruction_index,
+        signature,
+      );
+      if let Some(perp_event) = perp_event {
+        decoded_tx.perp_events.push(perp_event);
+      } else {
+        decoded_tx.push_unrecognized(
+          instruction,
+          block_time,
+          slot,
+          index,
+          *atomic_instruction_index,
+          signature,
+        );
+      }
+    } else if is_unhandled_supported_instruction(instruction) {
+      decoded_tx.push_unrecognized(
+        instruction,
+        block_time,
+        slot,
+        index,
+        *atomic_instruction_index,
+        signature,
+      );
     }
 
-    *atomic_instruction_index += 1;
+    *atomic_instruction_index = atomic_instruction_index.wrapping_add(1);
     instr_index += 1;
     // println!("Atomic instruction index (in inner): {:?}", *atomic_instruction_index);
   }
@@ -1,3 +1,18 @@
+pub mod analyze_failed_tx_source;
+pub mod analyze_pending_tx;
 pub mod analyze_tx;
+pub mod analyze_tx_source;
+pub mod apply_decode_filter;
+pub mod apply_swap_decimals;
+pub mod compute_tx_cost;
+pub mod compute_wallet_deltas;
+pub mod decode_failed_tx_source;
+pub mod decode_tx_source;
+pub mod detect_arbitrage;
+pub mod find_tips;
 pub mod inner_instructions_loop;
-pub mod top_level_instructions_loop;
\ No newline at end of file
+pub mod parse_program_logs;
+pub mod process_log_events;
+pub mod reconcile_running_token_balances;
+pub mod send_decoded_tx;
+pub mod top_level_instructions_loop;
@@ -0,0 +1,206 @@
+use crate::types::log_event::LogEvent;
+use crate::types::program_logs::ProgramLogs;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// Walk program log lines and collect every "Program data:" payload together with the invocation
+/// that logged it. Invocations are tracked with the "invoke [depth]" and "success" / "failed"
+/// lines, so each payload is attributed to its top level instruction and the index of the emitting
+/// inner instruction, which line up with the inner instructions of the status meta. An invocation
+/// only returns on a "success" / "failed" line of its own program, so program logged text can't
+/// end it. Payloads with a field that isn't valid base64 are dropped whole rather than decoded with
+/// a gap. Stops at "Log truncated" and flags the logs as truncated.
+pub fn parse_program_logs(log_messages: &[&str]) -> ProgramLogs {
+  let mut program_logs = ProgramLogs::default();
+  // Program and inner index of every invocation that has not returned yet
+  let mut invocations: Vec<(Pubkey, Option<u8>)> = Vec::new();
+  let mut top_level_index: Option<u8> = None;
+  let mut inner_count: u8 = 0;
+
+  for log_message in log_messages {
+    if *log_message == "Log truncated" {
+      program_logs.truncated = true;
+      break;
+    }
+    if let Some(payload) = log_message.strip_prefix("Program data: ") {
+      let (Some(top_level_index), Some((program_id, inner_index))) =
+        (top_level_index, invocations.last())
+      else {
+        continue;
+      };
+      let data = payload.split(' ').try_fold(Vec::new(), |mut data, field| {
+        data.extend(STANDARD.decode(field).ok()?);
+        Some(data)
+      });
+      let Some(data) = data else {
+        continue;
+      };
+      program_logs.events.push(LogEvent {
+        top_level_index,
+        inner_index: *inner_index,
+        program_id: *program_id,
+        invoke_depth: invocations.len() as u8,
+        data,
+      });
+      continue;
+    }
+    let Some(rest) = log_message.strip_prefix("Program ") else {
+      continue;
+    };
+    // "Program <id> invoke [<depth>]"
+    if let Some((program_id, depth)) = rest.split_once(" invoke [") {
+      let Ok(program_id) = Pubkey::from_str(program_id) else {
+        continue;
+      };
+      if depth == "1]" {
+        top_level_index =
+          Some(top_level_index.map_or(0, |top_level_index| top_level_index.saturating_add(1)));
+        inner_count = 0;
+        invocations.clear();
+        invocations.push((program_id, None));
+      } else {
+        invocations.push((program_id, Some(inner_count)));
+        inner_count = inner_count.saturating_add(1);
+      }
+    }
+    // "Program <id> success" or "Program <id> failed: <error>"
+    else {
+      let returned_program_id = rest
+        .strip_suffix(" success")
+        .or_else(|| {
+          rest
+            .split_once(" failed: ")
+            .map(|(program_id, _)| program_id)
+        })
+        .and_then(|program_id| Pubkey::from_str(program_id).ok());
+      let is_return_of_last_invocation = returned_program_id.is_some_and(|returned_program_id| {
+        invocations
+          .last()
+          .is_some_and(|(program_id, _)| *program_id == returned_program_id)
+      });
+      if is_return_of_last_invocation {
+        invocations.pop();
+      }
+    }
+  }
+  program_logs
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn attributes_payloads_to_their_invocation() {
+    let program_a = Pubkey::new_unique();
+    let program_b = Pubkey::new_unique();
+    let program_c = Pubkey::new_unique();
+    let data = STANDARD.encode([1, 2, 3]);
+    let more_data = STANDARD.encode([4, 5]);
+    let log_messages = [
+      format!("Program {program_a} invoke [1]"),
+      format!("Program data: {data}"),
+      format!("Program {program_b} invoke [2]"),
+      format!("Program {program_c} invoke [3]"),
+      format!("Program data: {data} {more_data}"),
+      format!("Program {program_c} success"),
+      format!("Program {program_b} failed: custom program error: 0x1"),
+      format!("Program {program_c} invoke [2]"),
+      format!("Program data: {more_data}"),
+      format!("Program {program_c} success"),
+      format!("Program {program_a} success"),
+      format!("Program {program_b} invoke [1]"),
+      format!("Program data: {data}"),
+      format!("Program {program_b} success"),
+    ];
+    let log_messages: Vec<&str> = log_messages.iter().map(String::as_str).collect();
+    let program_logs = parse_program_logs(&log_messages);
+    assert!(!program_logs.truncated);
+    let events: Vec<_> = program_logs
+      .events
+      .iter()
+      .map(|event| {
+        (
+          event.top_level_index,
+          event.inner_index,
+          event.program_id,
+          event.invoke_depth,
+          event.data.clone(),
+        )
+      })
+      .collect();
+    assert_eq!(
+      events,
+      vec![
+        (0, None, program_a, 1, vec![1, 2, 3]),
+        (0, Some(1), program_c, 3, vec![1, 2, 3, 4, 5]),
+        (0, Some(2), program_c, 2, vec![4, 5]),
+        (1, None, program_b, 1, vec![1, 2, 3]),
+      ]
+    );
+  }
+
+  #[test]
+  fn program_logged_text_does_not_end_an_invocation() {
+    let program_a = Pubkey::new_unique();
+    let program_b = Pubkey::new_unique();
+    let data = STANDARD.encode([1, 2, 3]);
+    let log_messages = [
+      format!("Program {program_a} invoke [1]"),
+      format!("Program {program_b} invoke [2]"),
+      "Program log: transfer success".to_string(),
+      format!("Program log: {program_b} failed: slippage, retrying"),
+      format!("Program {program_a} success"),
+      format!("Program data: {data}"),
+      format!("Program {program_b} success"),
+      format!("Program data: {data}"),
+      format!("Program {program_a} success"),
+    ];
+    let log_messages: Vec<&str> = log_messages.iter().map(String::as_str).collect();
+    let program_logs = parse_program_logs(&log_messages);
+    let emitters: Vec<_> = program_logs
+      .events
+      .iter()
+      .map(|event| (event.program_id, event.inner_index, event.invoke_depth))
+      .collect();
+    assert_eq!(
+      emitters,
+      vec![(program_b, Some(0), 2), (program_a, None, 1)]
+    );
+  }
+
+  #[test]
+  fn stops_at_log_truncation() {
+    let program = Pubkey::new_unique();
+    let data = STANDARD.encode([1]);
+    let log_messages = [
+      format!("Program {program} invoke [1]"),
+      format!("Program data: {data}"),
+      "Log truncated".to_string(),
+      format!("Program data: {data}"),
+    ];
+    let log_messages: Vec<&str> = log_messages.iter().map(String::as_str).collect();
+    let program_logs = parse_program_logs(&log_messages);
+    assert!(program_logs.truncated);
+    assert_eq!(program_logs.events.len(), 1);
+  }
+
+  #[test]
+  fn invalid_base64_drops_the_whole_payload() {
+    let program = Pubkey::new_unique();
+    let data = STANDARD.encode([1, 2, 3]);
+    let log_messages = [
+      format!("Program {program} invoke [1]"),
+      format!("Program data: {data} not*base64"),
+      format!("Program data: {data}"),
+    ];
+    let mut log_messages: Vec<&str> = log_messages.iter().map(String::as_str).collect();
+    // Payloads before the first invocation have no instruction to attribute them to
+    log_messages.insert(0, "Program data: AQID");
+    let program_logs = parse_program_logs(&log_messages);
+    assert_eq!(program_logs.events.len(), 1);
+    assert_eq!(program_logs.events[0].data, vec![1, 2, 3]);
+  }
+}
@@ -0,0 +1,236 @@
+use crate::constants::discriminators::ANCHOR_EVENT_CPI_TAG;
+use crate::constants::event_layouts::PF_TRADE_EVENT_DISCRIMINATOR;
+use crate::instruction::pumpfun::is_pumpfun_event_instruction::is_pumpfun_event_instruction;
+use crate::instruction::pumpfun::process_pumpfun_event_instruction::process_pumpfun_event_instruction;
+use crate::types::decoded_tx::DecodedTx;
+use crate::types::log_event::LogEvent;
+use solana_central::Instruction;
+use solana_central::constants::PUMP_CONSTANTS;
+use solana_central::constants::RAYDIUM_CONSTANTS;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Decode events from program logs into `decoded_tx` as a fallback for invocations whose events
+/// are not available as self CPI inner instructions, such as Pumpfun trades from before events
+/// were emitted with self CPI. Log payloads are fed to the same processors as inner instruction
+/// events, with the event CPI tag prepended. Swaps are kept in atomic instruction order. Pumpswap
+/// and Raydium launchpad only emit events with self CPI, so a logged event of theirs has no decoder
+/// and is pushed as an `UnrecognizedInstruction`, unless the same event was also emitted with self
+/// CPI. Raydium CLMM and Orca have no log decoder yet.
+pub fn process_log_events(
+  log_events: &[LogEvent],
+  top_level_instructions: &Vec<Instruction>,
+  inner_instructions: &HashMap<u8, Vec<Instruction>>,
+  account_keys: &Vec<Pubkey>,
+  decoded_tx: &mut DecodedTx,
+  block_time: u64,
+  slot: u64,
+  index: u64,
+  signers: &HashSet<Pubkey>,
+  signature: &Signature,
+) {
+  let swaps_before = decoded_tx.swaps.len();
+  // Atomic instruction index of each top level instruction, its inner instructions follow it
+  let mut top_level_atomic_indexes = Vec::with_capacity(top_level_instructions.len());
+  let mut atomic_instruction_index: u8 = 0;
+  for instr_index in 0..top_level_instructions.len() {
+    top_level_atomic_indexes.push(atomic_instruction_index);
+    let inner_len = inner_instructions
+      .get(&(instr_index as u8))
+      .map_or(0, Vec::len);
+    atomic_instruction_index = atomic_instruction_index
+      .wrapping_add(inner_len as u8)
+      .wrapping_add(1);
+  }
+
+  for log_event in log_events {
+    let is_pumpfun_trade = log_event.program_id == PUMP_CONSTANTS.bonding_curve_program
+      && log_event
+        .data
+        .starts_with(&PF_TRADE_EVENT_DISCRIMINATOR[8..]);
+    let is_undecodable = log_event.program_id == PUMP_CONSTANTS.pump_swap_program
+      || log_event.program_id == RAYDIUM_CONSTANTS.launchpad_program;
+    if !is_pumpfun_trade && !is_undecodable {
+      continue;
+    }
+    let inner = inner_instructions.get(&log_event.top_level_index);
+    // The invocation that logged the event, for Pumpfun the buy or sell instruction itself
+    let swap_instruction = match log_event.inner_index {
+      Some(inner_index) => inner.and_then(|inner| inner.get(inner_index as usize)),
+      None => top_level_instructions.get(log_event.top_level_index as usize),
+    };
+    let Some(swap_instruction) = swap_instruction else {
+      continue;
+    };
+    let Some(top_level_atomic_index) =
+      top_level_atomic_indexes.get(log_event.top_level_index as usize)
+    else {
+      continue;
+    };
+    // Atomic indexes wrap like in the instruction loops
+    let atomic_instruction_index = top_level_atomic_index.wrapping_add(
+      log_event
+        .inner_index
+        .map_or(0, |inner_index| inner_index.wrapping_add(1)),
+    );
+
+    let mut data = ANCHOR_EVENT_CPI_TAG.to_vec();
+    data.extend_from_slice(&log_event.data);
+    // Log events have no accounts, unlike self CPI events which pass the event authority
+    let accounts = Vec::new();
+    let event = Instruction {
+      tx_account_keys: account_keys,
+      accounts: &accounts,
+      data: &data,
+      program_id_index: swap_instruction.program_id_index,
+    };
+    if is_undecodable {
+      // The same event emitted with self CPI was already decoded by the instruction loops
+      let is_self_cpi_event = |instruction: &Instruction| {
+        instruction
+          .tx_account_keys
+          .get(instruction.program_id_index as usize)
+          == Some(&log_event.program_id)
+          && instruction.data.starts_with(&ANCHOR_EVENT_CPI_TAG)
+          && instruction.data[ANCHOR_EVENT_CPI_TAG.len()..] == log_event.data[..]
+      };
+      if inner.is_some_and(|inner| inner.iter().any(is_self_cpi_event)) {
+        continue;
+      }
+      decoded_tx.push_unrecognized(
+        &event,
+        block_time,
+        slot,
+        index,
+        atomic_instruction_index,
+        signature,
+      );
+      continue;
+    }
+    // Trades of this top level instruction were already decoded from self CPI events
+    if inner.is_some_and(|inner| inner.iter().any(is_pumpfun_event_instruction)) {
+      continue;
+    }
+    let swap = process_pumpfun_event_instruction(
+      &event,
+      Some(swap_instruction),
+      block_time,
+      slot,
+      index,
+      atomic_instruction_index,
+      signers,
+      signature,
+    );
+    decoded_tx.push_swap_or_unrecognized(
+      swap,
+      &event,
+      block_time,
+      slot,
+      index,
+      atomic_instruction_index,
+      signature,
+    );
+  }
+
+  // Log events are decoded after the instruction loops, restore atomic instruction order
+  if decoded_tx.swaps.len() > swaps_before {
+    let mut swaps: Vec<_> = decoded_tx
+      .swaps
+      .drain(..)
+      .zip(decoded_tx.swap_details.drain(..))
+      .collect();
+    swaps.sort_by_key(|(swap_tx, _)| swap_tx.atomic_instruction_index);
+    (decoded_tx.swaps, decoded_tx.swap_details) = swaps.into_iter().unzip();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn log_event(top_level_index: u8, program_id: Pubkey) -> LogEvent {
+    LogEvent {
+      top_level_index,
+      inner_index: None,
+      program_id,
+      invoke_depth: 1,
+      data: vec![1, 2, 3],
+    }
+  }
+
+  #[test]
+  fn logged_events_without_a_decoder_are_unrecognized() {
+    let account_keys = vec![Pubkey::new_unique(), PUMP_CONSTANTS.pump_swap_program];
+    let accounts = vec![0];
+    let data = vec![9; 24];
+    let top_level_instructions = vec![Instruction {
+      tx_account_keys: &account_keys,
+      accounts: &accounts,
+      data: &data,
+      program_id_index: 1,
+    }];
+    let log_events = [
+      log_event(0, PUMP_CONSTANTS.pump_swap_program),
+      // Programs without events of interest and events of missing instructions are ignored
+      log_event(0, Pubkey::new_unique()),
+      log_event(5, PUMP_CONSTANTS.pump_swap_program),
+    ];
+    let mut decoded_tx = DecodedTx::default();
+    process_log_events(
+      &log_events,
+      &top_level_instructions,
+      &HashMap::new(),
+      &account_keys,
+      &mut decoded_tx,
+      0,
+      1,
+      0,
+      &HashSet::new(),
+      &Signature::new_unique(),
+    );
+    assert!(decoded_tx.swaps.is_empty());
+    assert_eq!(decoded_tx.unrecognized_instructions.len(), 1);
+    let unrecognized = &decoded_tx.unrecognized_instructions[0];
+    assert_eq!(unrecognized.program_id, PUMP_CONSTANTS.pump_swap_program);
+    assert_eq!(unrecognized.data[ANCHOR_EVENT_CPI_TAG.len()..], [1, 2, 3]);
+    assert_eq!(unrecognized.atomic_instruction_index, 0);
+  }
+
+  #[test]
+  fn logged_events_also_emitted_with_self_cpi_are_skipped() {
+    let account_keys = vec![Pubkey::new_unique(), PUMP_CONSTANTS.pump_swap_program];
+    let accounts = vec![0];
+    let data = vec![9; 24];
+    let top_level_instructions = vec![Instruction {
+      tx_account_keys: &account_keys,
+      accounts: &accounts,
+      data: &data,
+      program_id_index: 1,
+    }];
+    let mut event_data = ANCHOR_EVENT_CPI_TAG.to_vec();
+    event_data.extend_from_slice(&[1, 2, 3]);
+    let event = Instruction {
+      tx_account_keys: &account_keys,
+      accounts: &accounts,
+      data: &event_data,
+      program_id_index: 1,
+    };
+    let inner_instructions = HashMap::from([(0, vec![event])]);
+    let mut decoded_tx = DecodedTx::default();
+    process_log_events(
+      &[log_event(0, PUMP_CONSTANTS.pump_swap_program)],
+      &top_level_instructions,
+      &inner_instructions,
+      &account_keys,
+      &mut decoded_tx,
+      0,
+      1,
+      0,
+      &HashSet::new(),
+      &Signature::new_unique(),
+    );
+    assert!(decoded_tx.unrecognized_instructions.is_empty());
+  }
+}
@@ -0,0 +1,109 @@
+use crate::types::balance_mismatch::BalanceMismatch;
+use crate::types::running_token_balances::RunningTokenBalances;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashMap;
+
+/// Compare the final running balance of every token account changed by decoded swaps against its
+/// post token balance and return the ones that differ. An account without a post token balance was
+/// closed and is expected to be empty. `ta_mint` maps token account indexes to their mint from the
+/// pre and post token balances, so closed accounts keep their mint. Usable in tests to catch
+/// decoders that miss transfers and in production as a drift metric.
+pub fn reconcile_running_token_balances(
+  running_token_balances: &RunningTokenBalances,
+  ta_mint: &HashMap<u8, Pubkey>,
+  account_keys: &[Pubkey],
+  signature: &Signature,
+  block_time: u64,
+  slot: u64,
+  index: u64,
+) -> Vec<BalanceMismatch> {
+  let mut mismatches = Vec::new();
+  for (account_index, atomic_instruction_index) in running_token_balances.touched() {
+    let expected = running_token_balances[&account_index];
+    let actual = running_token_balances.get_post(account_index).unwrap_or(0);
+    if expected == actual {
+      continue;
+    }
+    mismatches.push(BalanceMismatch {
+      signature: *signature,
+      block_time,
+      slot,
+      index,
+      token_account: account_keys
+        .get(account_index as usize)
+        .copied()
+        .unwrap_or_default(),
+      mint: ta_mint.get(&account_index).copied().unwrap_or_default(),
+      expected,
+      actual,
+      atomic_instruction_index,
+    });
+  }
+  mismatches.sort_by_key(|mismatch| mismatch.atomic_instruction_index);
+  mismatches
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::types::running_token_balances::RunningTokenBalances;
+
+  #[test]
+  fn reports_drifted_and_closed_accounts_with_their_mint() {
+    let account_keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+    let mint = Pubkey::new_unique();
+    let closed_mint = Pubkey::new_unique();
+    let ta_mint = HashMap::from([(1, mint), (2, closed_mint), (3, mint)]);
+    let mut running_token_balances = RunningTokenBalances::new();
+    for account_index in 1..=3 {
+      running_token_balances.insert(account_index, 1_000);
+    }
+    running_token_balances.insert_post(1, 900);
+    running_token_balances.insert_post(3, 1_000);
+    // Account 2 was closed and has no post token balance
+    running_token_balances.debit(1, 100, 4);
+    running_token_balances.debit(2, 600, 7);
+    running_token_balances.credit(3, 50, 2);
+
+    let signature = Signature::new_unique();
+    let mismatches = reconcile_running_token_balances(
+      &running_token_balances,
+      &ta_mint,
+      &account_keys,
+      &signature,
+      1,
+      2,
+      3,
+    );
+    assert_eq!(mismatches.len(), 2);
+    assert_eq!(mismatches[0].token_account, account_keys[3]);
+    assert_eq!(mismatches[0].mint, mint);
+    assert_eq!(mismatches[0].expected, 1_050);
+    assert_eq!(mismatches[0].actual, 1_000);
+    assert_eq!(mismatches[0].atomic_instruction_index, 2);
+    assert_eq!(mismatches[1].token_account, account_keys[2]);
+    assert_eq!(mismatches[1].mint, closed_mint);
+    assert_eq!(mismatches[1].expected, 400);
+    assert_eq!(mismatches[1].actual, 0);
+    assert_eq!(mismatches[1].signature, signature);
+  }
+
+  #[test]
+  fn untouched_accounts_are_not_reconciled() {
+    let account_keys = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut running_token_balances = RunningTokenBalances::new();
+    running_token_balances.insert(1, 1_000);
+    running_token_balances.insert_post(1, 0);
+    let mismatches = reconcile_running_token_balances(
+      &running_token_balances,
+      &HashMap::new(),
+      &account_keys,
+      &Signature::new_unique(),
+      1,
+      2,
+      3,
+    );
+    assert!(mismatches.is_empty());
+  }
+}
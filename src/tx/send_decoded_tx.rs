@@ -0,0 +1,48 @@
+use crate::types::decode_senders::DecodeSenders;
+use crate::types::decoded_tx::DecodedTx;
+
+/// Write everything decoded from a transaction to the broadcast channels in `senders`. Send errors
+/// only mean there are no receivers for that output type and are ignored.
+pub fn send_decoded_tx(decoded_tx: DecodedTx, senders: &DecodeSenders) {
+  for swap_tx in decoded_tx.swaps {
+    let _ = senders.swap_tx_sender.send(swap_tx);
+  }
+  for swap_details in decoded_tx.swap_details {
+    let _ = senders.swap_details_sender.send(swap_details);
+  }
+  for creation in decoded_tx.token_creations {
+    let _ = senders.token_create_sender.send(creation);
+  }
+  for pending_swap in decoded_tx.pending_swaps {
+    let _ = senders.pending_swap_sender.send(pending_swap);
+  }
+  for failed_swap_attempt in decoded_tx.failed_swap_attempts {
+    let _ = senders.failed_swap_attempt_sender.send(failed_swap_attempt);
+  }
+  if let Some(tx_cost) = decoded_tx.tx_cost {
+    let _ = senders.tx_cost_sender.send(tx_cost);
+  }
+  for arbitrage in decoded_tx.arbitrages {
+    let _ = senders.arbitrage_sender.send(arbitrage);
+  }
+  for route_trade in decoded_tx.route_trades {
+    let _ = senders.route_trade_sender.send(route_trade);
+  }
+  for perp_event in decoded_tx.perp_events {
+    let _ = senders.perp_event_sender.send(perp_event);
+  }
+  for wallet_delta in decoded_tx.wallet_deltas {
+    let _ = senders.wallet_delta_sender.send(wallet_delta);
+  }
+  for balance_mismatch in decoded_tx.balance_mismatches {
+    let _ = senders.balance_mismatch_sender.send(balance_mismatch);
+  }
+  for unrecognized_instruction in decoded_tx.unrecognized_instructions {
+    let _ = senders
+      .unrecognized_instruction_sender
+      .send(unrecognized_instruction);
+  }
+  for log_truncation in decoded_tx.log_truncations {
+    let _ = senders.log_truncation_sender.send(log_truncation);
+  }
+}
@@ -1,31 +1,35 @@
 use crate::instruction::classify_instruction::classify_instruction;
+use crate::instruction::is_unhandled_supported_instruction::is_unhandled_supported_instruction;
 use crate::instruction::pumpfun::process_pf_bonding_curve_create_instruction::process_pf_bonding_curve_create_instruction;
 use crate::instruction::pumpswap::process_pumpswap_swap_instruction::process_pumpswap_swap_instruction;
 use crate::instruction::raydium::process_raydium_ammv4_swap_instruction::process_raydium_ammv4_swap_instruction;
 use crate::instruction::raydium::process_raydium_cpmm_swap_instruction::process_raydium_cpmm_swap_instruction;
 use crate::instruction::raydium::process_raydium_launchpad_swap_instruction::process_raydium_launchpad_swap_instruction;
+use crate::instruction::router::classify_router_instruction::classify_router_instruction;
+use crate::instruction::router::process_router_instruction::process_router_instruction;
 use crate::tx::inner_instructions_loop::inner_instructions_loop;
+use crate::types::decoded_tx::DecodedTx;
 use crate::types::instruction_type::InstructionType;
+use crate::types::running_token_balances::RunningTokenBalances;
 use solana_central::Instruction;
-use solana_central::SwapTx;
-use solana_central::TokenCreation;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use std::collections::HashMap;
 use std::collections::HashSet;
-use tokio::sync::broadcast::Sender;
 
 /// Process top-level instructions in a transaction. Iterates through top-level instructions,
-/// classifies them, and processes swap/creation instructions. Also calls `inner_instructions_loop`
-/// to process the inner instructions that belong to each top level instruction.
+/// classifies them, and processes swap/creation instructions into `decoded_tx`. Router instructions
+/// are also processed into `RouteTrade`s once their inner instructions are decoded. Also calls
+/// `inner_instructions_loop` to process the inner instructions that belong to each top level
+/// instruction. Instructions of supported programs that can't be decoded, including ones whose
+/// inner instructions are missing, are pushed as `UnrecognizedInstruction`s.
 pub fn top_level_instructions_loop(
   top_level_instructions: &Vec<Instruction>,
   inner_instructions: &HashMap<u8, Vec<Instruction>>,
   account_keys: &Vec<Pubkey>,
   ta_mint: &HashMap<u8, Pubkey>,
-  running_token_balances: &mut HashMap<u8, u64>,
-  swap_tx_sender: &Sender<SwapTx>,
-  token_create_sender: &Sender<TokenCreation>,
+  running_token_balances: &mut RunningTokenBalances,
+  decoded_tx: &mut DecodedTx,
   block_time: u64,
   slot: u64,
   index: u64,
@@ -36,20 +40,32 @@ pub fn top_level_instructions_loop(
   for (instr_index, instruction) in top_level_instructions.iter().enumerate() {
     let instr_index = instr_index as u8;
     let (instruction_type, swap_direction) = classify_instruction(&instruction);
+    let inner = inner_instructions.get(&instr_index);
     // println!("Instruction type: {:?}", instruction_type);
     // println!("Instruction type: {:?}", instruction_type);
     // println!("Atomic instruction index: {:?}", atomic_instruction_index);
     if instruction_type == InstructionType::None {
+      if is_unhandled_supported_instruction(instruction) {
+        decoded_tx.push_unrecognized(
+          instruction,
+          block_time,
+          slot,
+          index,
+          atomic_instruction_index,
+          signature,
+        );
+      }
       // Bump by 1, if its not none it will be bumped by 1 again adn the length of the inners
-      atomic_instruction_index += 1;
-      if let Some(inner_instructions) = inner_instructions.get(&instr_index) {
+      atomic_instruction_index = atomic_instruction_index.wrapping_add(1);
+      if let Some(inner_instructions) = inner {
+        let swaps_before = decoded_tx.swaps.len();
         inner_instructions_loop(
           inner_instructions,
+          instruction,
           account_keys,
           ta_mint,
           running_token_balances,
-          swap_tx_sender,
-          token_create_sender,
+          decoded_tx,
           block_time,
           slot,
           index,
@@ -57,24 +73,52 @@ pub fn top_level_instructions_loop(
           signers,
           signature,
         );
+        // Aggregator routes are also emitted as the user level trade of their child swaps
+        if let Some(router) = classify_router_instruction(instruction) {
+          let route_trade = process_router_instruction(
+            router,
+            instruction,
+            inner_instructions,
+            &decoded_tx.swaps[swaps_before..],
+            block_time,
+            slot,
+            index,
+            instr_index,
+            signers,
+            signature,
+          );
+          if let Some(route_trade) = route_trade {
+            decoded_tx.route_trades.push(route_trade);
+          }
+        }
       }
     } else if instruction_type == InstructionType::RaydiumLaunchpadSwap {
-      let event = &inner_instructions.get(&instr_index).unwrap()[0];
-      let swap_tx = process_raydium_launchpad_swap_instruction(
+      // Event instruction is the first inner instruction of the swap
+      let swap = inner.and_then(|inner| inner.first()).and_then(|event| {
+        process_raydium_launchpad_swap_instruction(
+          instruction,
+          event,
+          swap_direction,
+          block_time,
+          slot,
+          index,
+          atomic_instruction_index,
+          signers,
+          signature,
+        )
+      });
+      decoded_tx.push_swap_or_unrecognized(
+        swap,
         instruction,
-        event,
-        swap_direction,
         block_time,
         slot,
         index,
         atomic_instruction_index,
-        signers,
         signature,
       );
-      let _ = swap_tx_sender.send(swap_tx);
     } else if instruction_type == InstructionType::RaydiumCpmmSwap {
-      let transfers = inner_instructions.get(&instr_index).unwrap();
-      let swap_tx = process_raydium_cpmm_swap_instruction(
+      let transfers = inner.map_or(&[][..], Vec::as_slice);
+      let swap = process_raydium_cpmm_swap_instruction(
         instruction,
         transfers,
         running_token_balances,
@@ -85,10 +129,18 @@ pub fn top_level_instructions_loop(
         signers,
         signature,
       );
-      let _ = swap_tx_sender.send(swap_tx);
+      decoded_tx.push_swap_or_unrecognized(
+        swap,
+        instruction,
+        block_time,
+        slot,
+        index,
+        atomic_instruction_index,
+        signature,
+      );
     } else if instruction_type == InstructionType::RaydiumAmmV4Swap {
-      let transfers = inner_instructions.get(&instr_index).unwrap();
-      let swap_tx = process_raydium_ammv4_swap_instruction(
+      let transfers = inner.map_or(&[][..], Vec::as_slice);
+      let swap = process_raydium_ammv4_swap_instruction(
         instruction,
         transfers,
         ta_mint,
@@ -100,25 +152,40 @@ pub fn top_level_instructions_loop(
         signers,
         signature,
       );
-      let _ = swap_tx_sender.send(swap_tx);
-    } else if instruction_type == InstructionType::PumpswapSwap {
-      let event = inner_instructions
-        .get(&instr_index)
-        .unwrap()
-        .last()
-        .unwrap();
-      let swap_tx = process_pumpswap_swap_instruction(
+      decoded_tx.push_swap_or_unrecognized(
+        swap,
         instruction,
-        event,
-        swap_direction,
         block_time,
         slot,
         index,
         atomic_instruction_index,
-        signers,
         signature,
       );
-      let _ = swap_tx_sender.send(swap_tx);
+    } else if instruction_type == InstructionType::PumpswapSwap {
+      // The event is the last inner instruction of the swap
+      let event = inner.and_then(|inner| inner.last());
+      let swap = event.and_then(|event| {
+        process_pumpswap_swap_instruction(
+          instruction,
+          event,
+          swap_direction,
+          block_time,
+          slot,
+          index,
+          atomic_instruction_index,
+          signers,
+          signature,
+        )
+      });
+      decoded_tx.push_swap_or_unrecognized(
+        swap,
+        event.unwrap_or(instruction),
+        block_time,
+        slot,
+        index,
+        atomic_instruction_index,
+        signature,
+      );
     } else if instruction_type == InstructionType::PfBondingCurveCreate {
       let creation = process_pf_bonding_curve_create_instruction(
         instruction,
@@ -128,13 +195,24 @@ pub fn top_level_instructions_loop(
         atomic_instruction_index,
         signature,
       );
-      let _ = token_create_sender.send(creation);
+      if let Some(creation) = creation {
+        decoded_tx.token_creations.push(creation);
+      } else {
+        decoded_tx.push_unrecognized(
+          instruction,
+          block_time,
+          slot,
+          index,
+          atomic_instruction_index,
+          signature,
+        );
+      }
     }
     // Add to atomic instruction index if not None since the top level swaps don't iterate through
     if instruction_type != InstructionType::None {
-      let a = inner_instructions.get(&instr_index).unwrap().len() as u8;
+      let a = inner.map_or(0, Vec::len) as u8;
       // println!("Inner instructions length: {:?}", a);
-      atomic_instruction_index += a + 1;
+      atomic_instruction_index = atomic_instruction_index.wrapping_add(a).wrapping_add(1);
     }
     // Pf bonding curve is not in here. Its not possible for a pf swap event be in a top level instruction
   }
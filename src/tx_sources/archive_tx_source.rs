@@ -0,0 +1,83 @@
+use crate::address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+use crate::tx_sources::complete_account_keys::complete_account_keys;
+use crate::tx_sources::storage_meta::storage_meta_err;
+use crate::tx_sources::storage_meta::storage_meta_inner_instructions;
+use crate::tx_sources::storage_meta::storage_meta_loaded_addresses;
+use crate::tx_sources::storage_meta::storage_meta_token_balances;
+use crate::tx_sources::tx_source::TxSource;
+use crate::tx_sources::versioned_tx::versioned_tx_top_level_instructions;
+use crate::types::archive_tx::ArchiveTx;
+use crate::types::inner_instruction::InnerInstruction;
+use crate::types::token_balance::TokenBalance;
+use bumpalo::Bump;
+use solana_central::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::TransactionError;
+
+impl TxSource for ArchiveTx<'_> {
+  fn account_keys(&self, resolver: Option<&dyn AddressLookupTableResolver>) -> Option<Vec<Pubkey>> {
+    complete_account_keys(
+      self.tx.message.static_account_keys().to_vec(),
+      storage_meta_loaded_addresses(self.meta),
+      self.tx.message.address_table_lookups().unwrap_or(&[]),
+      resolver,
+    )
+  }
+
+  fn num_required_signatures(&self) -> u8 {
+    self.tx.message.header().num_required_signatures
+  }
+
+  fn signature(&self) -> Signature {
+    self.tx.signatures[0]
+  }
+
+  fn is_err(&self) -> bool {
+    self.meta.err.is_some()
+  }
+
+  fn err(&self) -> Option<TransactionError> {
+    storage_meta_err(self.meta)
+  }
+
+  fn fee(&self) -> u64 {
+    self.meta.fee
+  }
+
+  fn compute_units_consumed(&self) -> Option<u64> {
+    self.meta.compute_units_consumed
+  }
+
+  fn top_level_instructions<'a>(&'a self, account_keys: &'a Vec<Pubkey>) -> Vec<Instruction<'a>> {
+    versioned_tx_top_level_instructions(self.tx, account_keys)
+  }
+
+  fn inner_instructions<'a>(
+    &'a self,
+    account_keys: &'a Vec<Pubkey>,
+    _arena: &'a Bump,
+  ) -> Vec<InnerInstruction<'a>> {
+    storage_meta_inner_instructions(self.meta, account_keys)
+  }
+
+  fn pre_balances(&self) -> Vec<u64> {
+    self.meta.pre_balances.clone()
+  }
+
+  fn post_balances(&self) -> Vec<u64> {
+    self.meta.post_balances.clone()
+  }
+
+  fn pre_token_balances(&self) -> Vec<TokenBalance> {
+    storage_meta_token_balances(&self.meta.pre_token_balances)
+  }
+
+  fn post_token_balances(&self) -> Vec<TokenBalance> {
+    storage_meta_token_balances(&self.meta.post_token_balances)
+  }
+
+  fn log_messages(&self) -> Vec<&str> {
+    self.meta.log_messages.iter().map(String::as_str).collect()
+  }
+}
@@ -0,0 +1,21 @@
+use crate::address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+use crate::address_lookup_table::resolve_address_table_lookups::resolve_address_table_lookups;
+use solana_sdk::message::v0::MessageAddressTableLookup;
+use solana_sdk::pubkey::Pubkey;
+
+/// Append loaded addresses to the static account keys of a transaction. Uses the addresses from
+/// status meta when it carries them, otherwise resolves `lookups` with `resolver`. Returns `None`
+/// if the transaction uses lookup tables that can't be resolved.
+pub fn complete_account_keys(
+  mut account_keys: Vec<Pubkey>,
+  meta_loaded_addresses: Vec<Pubkey>,
+  lookups: &[MessageAddressTableLookup],
+  resolver: Option<&dyn AddressLookupTableResolver>,
+) -> Option<Vec<Pubkey>> {
+  if !meta_loaded_addresses.is_empty() || lookups.is_empty() {
+    account_keys.extend(meta_loaded_addresses);
+    return Some(account_keys);
+  }
+  account_keys.extend(resolve_address_table_lookups(lookups, resolver?)?);
+  Some(account_keys)
+}
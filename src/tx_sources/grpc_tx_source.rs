@@ -0,0 +1,118 @@
+use crate::address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+use crate::tx_sources::complete_account_keys::complete_account_keys;
+use crate::tx_sources::tx_source::TxSource;
+use crate::tx_sources::yellowstone_meta::yellowstone_meta_err;
+use crate::tx_sources::yellowstone_meta::yellowstone_meta_inner_instructions;
+use crate::tx_sources::yellowstone_meta::yellowstone_meta_loaded_addresses;
+use crate::tx_sources::yellowstone_meta::yellowstone_meta_token_balances;
+use crate::types::grpc_tx::GrpcTx;
+use crate::types::inner_instruction::InnerInstruction;
+use crate::types::token_balance::TokenBalance;
+use bumpalo::Bump;
+use solana_central::Instruction;
+use solana_sdk::message::v0::MessageAddressTableLookup;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::TransactionError;
+
+fn bytes_to_pubkey(bytes: &[u8]) -> Pubkey {
+  bytes.try_into().map(Pubkey::new_from_array).unwrap()
+}
+
+impl TxSource for GrpcTx<'_> {
+  fn account_keys(&self, resolver: Option<&dyn AddressLookupTableResolver>) -> Option<Vec<Pubkey>> {
+    let message = self.tx.message.as_ref().unwrap();
+    let static_account_keys = message
+      .account_keys
+      .iter()
+      .map(|bytes| bytes_to_pubkey(bytes))
+      .collect();
+    let lookups: Vec<MessageAddressTableLookup> = message
+      .address_table_lookups
+      .iter()
+      .map(|lookup| MessageAddressTableLookup {
+        account_key: bytes_to_pubkey(&lookup.account_key),
+        writable_indexes: lookup.writable_indexes.clone(),
+        readonly_indexes: lookup.readonly_indexes.clone(),
+      })
+      .collect();
+    complete_account_keys(
+      static_account_keys,
+      yellowstone_meta_loaded_addresses(self.meta),
+      &lookups,
+      resolver,
+    )
+  }
+
+  fn num_required_signatures(&self) -> u8 {
+    self.tx.message.as_ref().unwrap().header.unwrap().num_required_signatures as u8
+  }
+
+  fn signature(&self) -> Signature {
+    Signature::from(
+      <[u8; 64]>::try_from(self.tx.signatures[0].as_slice())
+        .expect("GrpcTx: Signature should be 64 bytes"),
+    )
+  }
+
+  fn is_err(&self) -> bool {
+    self.meta.err.is_some()
+  }
+
+  fn err(&self) -> Option<TransactionError> {
+    yellowstone_meta_err(self.meta)
+  }
+
+  fn fee(&self) -> u64 {
+    self.meta.fee
+  }
+
+  fn compute_units_consumed(&self) -> Option<u64> {
+    self.meta.compute_units_consumed
+  }
+
+  fn top_level_instructions<'a>(&'a self, account_keys: &'a Vec<Pubkey>) -> Vec<Instruction<'a>> {
+    self
+      .tx
+      .message
+      .as_ref()
+      .unwrap()
+      .instructions
+      .iter()
+      .map(|raw_inst| Instruction {
+        tx_account_keys: account_keys,
+        accounts: &raw_inst.accounts,
+        data: &raw_inst.data,
+        program_id_index: raw_inst.program_id_index as u8,
+      })
+      .collect()
+  }
+
+  fn inner_instructions<'a>(
+    &'a self,
+    account_keys: &'a Vec<Pubkey>,
+    _arena: &'a Bump,
+  ) -> Vec<InnerInstruction<'a>> {
+    yellowstone_meta_inner_instructions(self.meta, account_keys)
+  }
+
+  fn pre_balances(&self) -> Vec<u64> {
+    self.meta.pre_balances.clone()
+  }
+
+  fn post_balances(&self) -> Vec<u64> {
+    self.meta.post_balances.clone()
+  }
+
+  fn pre_token_balances(&self) -> Vec<TokenBalance> {
+    yellowstone_meta_token_balances(&self.meta.pre_token_balances)
+  }
+
+  fn post_token_balances(&self) -> Vec<TokenBalance> {
+    yellowstone_meta_token_balances(&self.meta.post_token_balances)
+  }
+
+  fn log_messages(&self) -> Vec<&str> {
+    self.meta.log_messages.iter().map(String::as_str).collect()
+  }
+}
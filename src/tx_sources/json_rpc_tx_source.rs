@@ -0,0 +1,136 @@
+use crate::address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+use crate::tx_sources::complete_account_keys::complete_account_keys;
+use crate::tx_sources::tx_source::TxSource;
+use crate::tx_sources::versioned_tx::versioned_tx_top_level_instructions;
+use crate::types::inner_instruction::InnerInstruction;
+use crate::types::json_rpc_tx::JsonRpcTx;
+use crate::types::token_balance::TokenBalance;
+use bumpalo::Bump;
+use solana_central::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::TransactionError;
+use solana_transaction_status_client_types::UiInstruction;
+use solana_transaction_status_client_types::UiLoadedAddresses;
+use solana_transaction_status_client_types::UiTransactionTokenBalance;
+
+fn ui_token_balances(token_balances: Option<&Vec<UiTransactionTokenBalance>>) -> Vec<TokenBalance> {
+  token_balances
+    .unwrap()
+    .iter()
+    .map(|token_balance| TokenBalance {
+      account_index: token_balance.account_index,
+      mint: Pubkey::from_str_const(&token_balance.mint),
+      amount: token_balance.ui_token_amount.amount.parse::<u64>().unwrap(),
+      decimals: token_balance.ui_token_amount.decimals,
+      owner: Option::<&String>::from(token_balance.owner.as_ref())
+        .map(|owner| Pubkey::from_str_const(owner)),
+    })
+    .collect()
+}
+
+impl TxSource for JsonRpcTx<'_> {
+  fn account_keys(&self, resolver: Option<&dyn AddressLookupTableResolver>) -> Option<Vec<Pubkey>> {
+    let loaded_addresses: Option<&UiLoadedAddresses> = self.meta.loaded_addresses.as_ref().into();
+    let meta_loaded_addresses = match loaded_addresses {
+      Some(loaded_addresses) => loaded_addresses
+        .writable
+        .iter()
+        .chain(&loaded_addresses.readonly)
+        .map(|base58_string| Pubkey::from_str_const(base58_string))
+        .collect(),
+      None => Vec::new(),
+    };
+    complete_account_keys(
+      self.tx.message.static_account_keys().to_vec(),
+      meta_loaded_addresses,
+      self.tx.message.address_table_lookups().unwrap_or(&[]),
+      resolver,
+    )
+  }
+
+  fn num_required_signatures(&self) -> u8 {
+    self.tx.message.header().num_required_signatures
+  }
+
+  fn signature(&self) -> Signature {
+    self.tx.signatures[0]
+  }
+
+  fn is_err(&self) -> bool {
+    self.meta.err.is_some()
+  }
+
+  fn err(&self) -> Option<TransactionError> {
+    self.meta.err.clone().map(Into::into)
+  }
+
+  fn fee(&self) -> u64 {
+    self.meta.fee
+  }
+
+  fn compute_units_consumed(&self) -> Option<u64> {
+    self.meta.compute_units_consumed.clone().into()
+  }
+
+  fn top_level_instructions<'a>(&'a self, account_keys: &'a Vec<Pubkey>) -> Vec<Instruction<'a>> {
+    versioned_tx_top_level_instructions(self.tx, account_keys)
+  }
+
+  fn inner_instructions<'a>(
+    &'a self,
+    account_keys: &'a Vec<Pubkey>,
+    arena: &'a Bump,
+  ) -> Vec<InnerInstruction<'a>> {
+    let mut inner_instructions = Vec::new();
+    for inner_inst_set in self.meta.inner_instructions.as_ref().unwrap() {
+      for inner_inst_raw in &inner_inst_set.instructions {
+        match inner_inst_raw {
+          UiInstruction::Compiled(inner_inst_raw) => {
+            // inner instruction raw data is a base58 encoded string
+            let data = bs58::decode(&inner_inst_raw.data).into_vec().unwrap();
+            let data = arena.alloc_slice_copy(&data);
+            inner_instructions.push(InnerInstruction {
+              top_level_index: inner_inst_set.index,
+              instruction: Instruction {
+                tx_account_keys: account_keys,
+                accounts: &inner_inst_raw.accounts,
+                data,
+                program_id_index: inner_inst_raw.program_id_index,
+              },
+              stack_height: inner_inst_raw.stack_height,
+            });
+          }
+          UiInstruction::Parsed(_) => {
+            panic!("We should not be getting parsed instructions here");
+          }
+        }
+      }
+    }
+    inner_instructions
+  }
+
+  fn pre_balances(&self) -> Vec<u64> {
+    self.meta.pre_balances.clone()
+  }
+
+  fn post_balances(&self) -> Vec<u64> {
+    self.meta.post_balances.clone()
+  }
+
+  fn pre_token_balances(&self) -> Vec<TokenBalance> {
+    ui_token_balances(self.meta.pre_token_balances.as_ref().into())
+  }
+
+  fn post_token_balances(&self) -> Vec<TokenBalance> {
+    ui_token_balances(self.meta.post_token_balances.as_ref().into())
+  }
+
+  fn log_messages(&self) -> Vec<&str> {
+    let log_messages: Option<&Vec<String>> = self.meta.log_messages.as_ref().into();
+    match log_messages {
+      Some(log_messages) => log_messages.iter().map(String::as_str).collect(),
+      None => Vec::new(),
+    }
+  }
+}
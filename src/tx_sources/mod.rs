@@ -0,0 +1,11 @@
+pub mod archive_tx_source;
+pub mod complete_account_keys;
+pub mod grpc_tx_source;
+pub mod json_rpc_tx_source;
+pub mod raw_tx_source;
+pub mod storage_meta;
+#[cfg(test)]
+pub mod test_tx_source;
+pub mod tx_source;
+pub mod versioned_tx;
+pub mod yellowstone_meta;
@@ -0,0 +1,213 @@
+use crate::address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+use crate::tx_sources::complete_account_keys::complete_account_keys;
+use crate::tx_sources::storage_meta::storage_meta_err;
+use crate::tx_sources::storage_meta::storage_meta_inner_instructions;
+use crate::tx_sources::storage_meta::storage_meta_loaded_addresses;
+use crate::tx_sources::storage_meta::storage_meta_token_balances;
+use crate::tx_sources::tx_source::TxSource;
+use crate::tx_sources::versioned_tx::versioned_tx_top_level_instructions;
+use crate::tx_sources::yellowstone_meta::yellowstone_meta_err;
+use crate::tx_sources::yellowstone_meta::yellowstone_meta_inner_instructions;
+use crate::tx_sources::yellowstone_meta::yellowstone_meta_loaded_addresses;
+use crate::tx_sources::yellowstone_meta::yellowstone_meta_token_balances;
+use crate::types::inner_instruction::InnerInstruction;
+use crate::types::raw_tx::RawTx;
+use crate::types::raw_tx::RawTxMeta;
+use crate::types::token_balance::TokenBalance;
+use bumpalo::Bump;
+use solana_central::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::TransactionError;
+use solana_sdk::transaction::VersionedTransaction;
+
+/// A `RawTx` after its wire bytes have been deserialized. Created by `analyze_tx` only once it is
+/// known the transaction will be analyzed, so failed raw transactions are never deserialized unless
+/// `decode_failed_txs` is set.
+pub struct RawTxSource<'a> {
+  pub tx: VersionedTransaction,
+  pub meta: RawTxMeta<'a>,
+}
+
+impl<'a> RawTxSource<'a> {
+  /// Deserialize the bincode wire format bytes of a `RawTx`. Returns `None` if the bytes are not a
+  /// bincode serialized `VersionedTransaction` with at least one signature, or if the transaction
+  /// fails sanitization, e.g. an instruction indexes past the end of the account keys.
+  pub fn new(raw_tx: &RawTx<'a>) -> Option<Self> {
+    let tx: VersionedTransaction = bincode::deserialize(raw_tx.tx_bytes).ok()?;
+    if tx.signatures.is_empty() || tx.sanitize().is_err() {
+      return None;
+    }
+    Some(RawTxSource {
+      tx,
+      meta: raw_tx.meta,
+    })
+  }
+}
+
+/// Whether a raw transaction failed, checked before its bytes are deserialized
+pub fn is_raw_tx_err(raw_tx: &RawTx) -> bool {
+  match raw_tx.meta {
+    RawTxMeta::Storage(meta) => meta.err.is_some(),
+    RawTxMeta::Yellowstone(meta) => meta.err.is_some(),
+  }
+}
+
+impl TxSource for RawTxSource<'_> {
+  fn account_keys(&self, resolver: Option<&dyn AddressLookupTableResolver>) -> Option<Vec<Pubkey>> {
+    let meta_loaded_addresses = match self.meta {
+      RawTxMeta::Storage(meta) => storage_meta_loaded_addresses(meta),
+      RawTxMeta::Yellowstone(meta) => yellowstone_meta_loaded_addresses(meta),
+    };
+    complete_account_keys(
+      self.tx.message.static_account_keys().to_vec(),
+      meta_loaded_addresses,
+      self.tx.message.address_table_lookups().unwrap_or(&[]),
+      resolver,
+    )
+  }
+
+  fn num_required_signatures(&self) -> u8 {
+    self.tx.message.header().num_required_signatures
+  }
+
+  fn signature(&self) -> Signature {
+    self.tx.signatures[0]
+  }
+
+  fn is_err(&self) -> bool {
+    match self.meta {
+      RawTxMeta::Storage(meta) => meta.err.is_some(),
+      RawTxMeta::Yellowstone(meta) => meta.err.is_some(),
+    }
+  }
+
+  fn err(&self) -> Option<TransactionError> {
+    match self.meta {
+      RawTxMeta::Storage(meta) => storage_meta_err(meta),
+      RawTxMeta::Yellowstone(meta) => yellowstone_meta_err(meta),
+    }
+  }
+
+  fn fee(&self) -> u64 {
+    match self.meta {
+      RawTxMeta::Storage(meta) => meta.fee,
+      RawTxMeta::Yellowstone(meta) => meta.fee,
+    }
+  }
+
+  fn compute_units_consumed(&self) -> Option<u64> {
+    match self.meta {
+      RawTxMeta::Storage(meta) => meta.compute_units_consumed,
+      RawTxMeta::Yellowstone(meta) => meta.compute_units_consumed,
+    }
+  }
+
+  fn top_level_instructions<'a>(&'a self, account_keys: &'a Vec<Pubkey>) -> Vec<Instruction<'a>> {
+    versioned_tx_top_level_instructions(&self.tx, account_keys)
+  }
+
+  fn inner_instructions<'a>(
+    &'a self,
+    account_keys: &'a Vec<Pubkey>,
+    _arena: &'a Bump,
+  ) -> Vec<InnerInstruction<'a>> {
+    match self.meta {
+      RawTxMeta::Storage(meta) => storage_meta_inner_instructions(meta, account_keys),
+      RawTxMeta::Yellowstone(meta) => yellowstone_meta_inner_instructions(meta, account_keys),
+    }
+  }
+
+  fn pre_balances(&self) -> Vec<u64> {
+    match self.meta {
+      RawTxMeta::Storage(meta) => meta.pre_balances.clone(),
+      RawTxMeta::Yellowstone(meta) => meta.pre_balances.clone(),
+    }
+  }
+
+  fn post_balances(&self) -> Vec<u64> {
+    match self.meta {
+      RawTxMeta::Storage(meta) => meta.post_balances.clone(),
+      RawTxMeta::Yellowstone(meta) => meta.post_balances.clone(),
+    }
+  }
+
+  fn pre_token_balances(&self) -> Vec<TokenBalance> {
+    match self.meta {
+      RawTxMeta::Storage(meta) => storage_meta_token_balances(&meta.pre_token_balances),
+      RawTxMeta::Yellowstone(meta) => yellowstone_meta_token_balances(&meta.pre_token_balances),
+    }
+  }
+
+  fn post_token_balances(&self) -> Vec<TokenBalance> {
+    match self.meta {
+      RawTxMeta::Storage(meta) => storage_meta_token_balances(&meta.post_token_balances),
+      RawTxMeta::Yellowstone(meta) => yellowstone_meta_token_balances(&meta.post_token_balances),
+    }
+  }
+
+  fn log_messages(&self) -> Vec<&str> {
+    match self.meta {
+      RawTxMeta::Storage(meta) => meta.log_messages.iter().map(String::as_str).collect(),
+      RawTxMeta::Yellowstone(meta) => meta.log_messages.iter().map(String::as_str).collect(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use solana_sdk::message::Message;
+  use solana_sdk::message::VersionedMessage;
+  use solana_sdk::message::compiled_instruction::CompiledInstruction;
+  use yellowstone_grpc_proto::solana::storage::confirmed_block::TransactionStatusMeta;
+
+  #[test]
+  fn malformed_bytes_are_none() {
+    let meta = TransactionStatusMeta::default();
+    for tx_bytes in [&[][..], &[1, 2, 3][..], &[0xff; 64][..]] {
+      let raw_tx = RawTx {
+        tx_bytes,
+        meta: RawTxMeta::Yellowstone(&meta),
+      };
+      assert!(RawTxSource::new(&raw_tx).is_none());
+    }
+  }
+
+  #[test]
+  fn wire_format_transaction_deserializes() {
+    let meta = TransactionStatusMeta::default();
+    let tx = VersionedTransaction {
+      signatures: vec![Signature::new_unique()],
+      message: VersionedMessage::Legacy(Message::new(&[], Some(&Pubkey::new_unique()))),
+    };
+    let tx_bytes = bincode::serialize(&tx).unwrap();
+    let raw_tx = RawTx {
+      tx_bytes: &tx_bytes,
+      meta: RawTxMeta::Yellowstone(&meta),
+    };
+    let source = RawTxSource::new(&raw_tx).unwrap();
+    assert_eq!(source.signature(), tx.signatures[0]);
+  }
+
+  #[test]
+  fn out_of_range_program_id_index_is_none() {
+    let meta = TransactionStatusMeta::default();
+    let mut message = Message::new(&[], Some(&Pubkey::new_unique()));
+    message.instructions.push(CompiledInstruction {
+      program_id_index: 5,
+      accounts: vec![0],
+      data: vec![1],
+    });
+    let tx = VersionedTransaction {
+      signatures: vec![Signature::new_unique()],
+      message: VersionedMessage::Legacy(message),
+    };
+    let tx_bytes = bincode::serialize(&tx).unwrap();
+    let raw_tx = RawTx {
+      tx_bytes: &tx_bytes,
+      meta: RawTxMeta::Yellowstone(&meta),
+    };
+    assert!(RawTxSource::new(&raw_tx).is_none());
+  }
+}
@@ -0,0 +1,80 @@
+/*
+Helpers for status meta in the Solana storage protobuf format used by archive and raw txs. The
+Yellowstone protobuf is generated from the same schema, see `yellowstone_meta` for its twin.
+*/
+use crate::types::inner_instruction::InnerInstruction;
+use crate::types::token_balance::TokenBalance;
+use solana_central::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+use solana_storage_proto::convert::generated::TokenBalance as ProtoTokenBalance;
+use solana_storage_proto::convert::generated::TransactionStatusMeta;
+
+/// Loaded writable addresses followed by loaded readonly addresses
+pub fn storage_meta_loaded_addresses(meta: &TransactionStatusMeta) -> Vec<Pubkey> {
+  meta
+    .loaded_writable_addresses
+    .iter()
+    .chain(&meta.loaded_readonly_addresses)
+    .map(|bytes| {
+      bytes
+        .as_slice()
+        .try_into()
+        .map(Pubkey::new_from_array)
+        .unwrap()
+    })
+    .collect()
+}
+
+/// The error is stored as bincode serialized `TransactionError` bytes
+pub fn storage_meta_err(meta: &TransactionStatusMeta) -> Option<TransactionError> {
+  meta.err.as_ref().map(|err| {
+    bincode::deserialize(&err.err).expect("storage_meta_err: Unable to deserialize tx error")
+  })
+}
+
+pub fn storage_meta_inner_instructions<'a>(
+  meta: &'a TransactionStatusMeta,
+  account_keys: &'a Vec<Pubkey>,
+) -> Vec<InnerInstruction<'a>> {
+  let mut inner_instructions = Vec::new();
+  for inner_inst_set in &meta.inner_instructions {
+    for inner_inst_raw in &inner_inst_set.instructions {
+      inner_instructions.push(InnerInstruction {
+        top_level_index: inner_inst_set.index as u8,
+        instruction: Instruction {
+          tx_account_keys: account_keys,
+          accounts: &inner_inst_raw.accounts,
+          data: &inner_inst_raw.data,
+          program_id_index: inner_inst_raw.program_id_index as u8,
+        },
+        stack_height: inner_inst_raw.stack_height,
+      });
+    }
+  }
+  inner_instructions
+}
+
+pub fn storage_meta_token_balances(token_balances: &[ProtoTokenBalance]) -> Vec<TokenBalance> {
+  token_balances
+    .iter()
+    .map(|token_balance| TokenBalance {
+      account_index: token_balance.account_index as u8,
+      mint: Pubkey::from_str_const(&token_balance.mint),
+      amount: token_balance
+        .ui_token_amount
+        .as_ref()
+        .unwrap()
+        .amount
+        .parse::<u64>()
+        .unwrap(),
+      decimals: token_balance.ui_token_amount.as_ref().unwrap().decimals as u8,
+      // Empty string if the owner was not recorded
+      owner: if token_balance.owner.is_empty() {
+        None
+      } else {
+        Some(Pubkey::from_str_const(&token_balance.owner))
+      },
+    })
+    .collect()
+}
@@ -0,0 +1,143 @@
+use crate::address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+use crate::tx_sources::tx_source::TxSource;
+use crate::types::inner_instruction::InnerInstruction;
+use crate::types::token_balance::TokenBalance;
+use bumpalo::Bump;
+use solana_central::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::TransactionError;
+
+/// Compiled instruction owned by a `TestTxSource`
+pub struct TestInstruction {
+  pub program_id_index: u8,
+  pub accounts: Vec<u8>,
+  pub data: Vec<u8>,
+}
+
+/// Transaction with its status meta built by hand, for tests of the decoding core.
+pub struct TestTxSource {
+  pub account_keys: Vec<Pubkey>,
+  pub num_required_signatures: u8,
+  pub signature: Signature,
+  pub err: Option<TransactionError>,
+  pub fee: u64,
+  pub top_level_instructions: Vec<TestInstruction>,
+  /// Inner instructions with the index of the top level instruction that yielded them
+  pub inner_instructions: Vec<(u8, TestInstruction)>,
+  pub pre_balances: Vec<u64>,
+  pub post_balances: Vec<u64>,
+  pub pre_token_balances: Vec<TokenBalance>,
+  pub post_token_balances: Vec<TokenBalance>,
+  pub log_messages: Vec<String>,
+}
+
+impl TestTxSource {
+  /// Successful transaction signed by the first of `account_keys` without instructions
+  pub fn new(account_keys: Vec<Pubkey>) -> Self {
+    let num_accounts = account_keys.len();
+    TestTxSource {
+      account_keys,
+      num_required_signatures: 1,
+      signature: Signature::new_unique(),
+      err: None,
+      fee: 5000,
+      top_level_instructions: Vec::new(),
+      inner_instructions: Vec::new(),
+      pre_balances: vec![0; num_accounts],
+      post_balances: vec![0; num_accounts],
+      pre_token_balances: Vec::new(),
+      post_token_balances: Vec::new(),
+      log_messages: Vec::new(),
+    }
+  }
+}
+
+fn to_instruction<'a>(
+  instruction: &'a TestInstruction,
+  account_keys: &'a Vec<Pubkey>,
+) -> Instruction<'a> {
+  Instruction {
+    tx_account_keys: account_keys,
+    accounts: &instruction.accounts,
+    data: &instruction.data,
+    program_id_index: instruction.program_id_index,
+  }
+}
+
+impl TxSource for TestTxSource {
+  fn account_keys(
+    &self,
+    _resolver: Option<&dyn AddressLookupTableResolver>,
+  ) -> Option<Vec<Pubkey>> {
+    Some(self.account_keys.clone())
+  }
+
+  fn num_required_signatures(&self) -> u8 {
+    self.num_required_signatures
+  }
+
+  fn signature(&self) -> Signature {
+    self.signature
+  }
+
+  fn is_err(&self) -> bool {
+    self.err.is_some()
+  }
+
+  fn err(&self) -> Option<TransactionError> {
+    self.err.clone()
+  }
+
+  fn fee(&self) -> u64 {
+    self.fee
+  }
+
+  fn compute_units_consumed(&self) -> Option<u64> {
+    None
+  }
+
+  fn top_level_instructions<'a>(&'a self, account_keys: &'a Vec<Pubkey>) -> Vec<Instruction<'a>> {
+    self
+      .top_level_instructions
+      .iter()
+      .map(|instruction| to_instruction(instruction, account_keys))
+      .collect()
+  }
+
+  fn inner_instructions<'a>(
+    &'a self,
+    account_keys: &'a Vec<Pubkey>,
+    _arena: &'a Bump,
+  ) -> Vec<InnerInstruction<'a>> {
+    self
+      .inner_instructions
+      .iter()
+      .map(|(top_level_index, instruction)| InnerInstruction {
+        top_level_index: *top_level_index,
+        instruction: to_instruction(instruction, account_keys),
+        stack_height: Some(2),
+      })
+      .collect()
+  }
+
+  fn pre_balances(&self) -> Vec<u64> {
+    self.pre_balances.clone()
+  }
+
+  fn post_balances(&self) -> Vec<u64> {
+    self.post_balances.clone()
+  }
+
+  fn pre_token_balances(&self) -> Vec<TokenBalance> {
+    self.pre_token_balances.clone()
+  }
+
+  fn post_token_balances(&self) -> Vec<TokenBalance> {
+    self.post_token_balances.clone()
+  }
+
+  fn log_messages(&self) -> Vec<&str> {
+    self.log_messages.iter().map(String::as_str).collect()
+  }
+}
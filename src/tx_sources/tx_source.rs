@@ -0,0 +1,56 @@
+use crate::address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+use crate::types::inner_instruction::InnerInstruction;
+use crate::types::token_balance::TokenBalance;
+use bumpalo::Bump;
+use solana_central::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::TransactionError;
+
+/// Field access for a transaction and its status meta from a specific source. The decoding core in
+/// `decode_tx_source` is written once against this trait, new transaction sources plug in by
+/// implementing it.
+pub trait TxSource {
+  /// Full account key list: static account keys, followed by writable and then readonly addresses
+  /// loaded from lookup tables. Loaded addresses missing from the meta are resolved with
+  /// `resolver`. `None` if the list can't be reconstructed.
+  fn account_keys(&self, resolver: Option<&dyn AddressLookupTableResolver>) -> Option<Vec<Pubkey>>;
+
+  fn num_required_signatures(&self) -> u8;
+
+  /// First signature, which identifies the transaction
+  fn signature(&self) -> Signature;
+
+  /// Whether the transaction failed. Cheaper than decoding the error with `err`
+  fn is_err(&self) -> bool;
+
+  fn err(&self) -> Option<TransactionError>;
+
+  /// Total fee charged in lamports
+  fn fee(&self) -> u64;
+
+  /// `None` for metas from before compute units consumed was recorded
+  fn compute_units_consumed(&self) -> Option<u64>;
+
+  fn top_level_instructions<'a>(&'a self, account_keys: &'a Vec<Pubkey>) -> Vec<Instruction<'a>>;
+
+  /// Inner instructions of all top level instructions in execution order. `arena` is available
+  /// for sources that need to decode instruction data before it can be referenced.
+  fn inner_instructions<'a>(
+    &'a self,
+    account_keys: &'a Vec<Pubkey>,
+    arena: &'a Bump,
+  ) -> Vec<InnerInstruction<'a>>;
+
+  /// Lamport balances of all account keys before the transaction, in account key order
+  fn pre_balances(&self) -> Vec<u64>;
+
+  fn post_balances(&self) -> Vec<u64>;
+
+  fn pre_token_balances(&self) -> Vec<TokenBalance>;
+
+  fn post_token_balances(&self) -> Vec<TokenBalance>;
+
+  /// Program log lines in execution order, empty if logs were not recorded
+  fn log_messages(&self) -> Vec<&str>;
+}
@@ -0,0 +1,23 @@
+/*
+Helpers for the transaction half of sources that carry a `VersionedTransaction` (archive, JSON RPC
+and raw txs).
+*/
+use solana_central::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+
+pub fn versioned_tx_top_level_instructions<'a>(
+  tx: &'a VersionedTransaction,
+  account_keys: &'a Vec<Pubkey>,
+) -> Vec<Instruction<'a>> {
+  tx.message
+    .instructions()
+    .iter()
+    .map(|raw_inst| Instruction {
+      tx_account_keys: account_keys,
+      accounts: &raw_inst.accounts,
+      data: &raw_inst.data,
+      program_id_index: raw_inst.program_id_index,
+    })
+    .collect()
+}
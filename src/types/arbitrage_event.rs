@@ -0,0 +1,26 @@
+use solana_central::SwapTx;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashSet;
+
+/// Swaps in a single transaction that form a closed cycle, found by `detect_arbitrage`. Each leg
+/// spends the token the previous one received and the last leg receives the token the first one
+/// spent. A leg is one swap or consecutive swaps between the same tokens split across pools.
+#[derive(Clone, Debug)]
+pub struct ArbitrageEvent {
+  pub signature: Signature,
+  pub block_time: u64,
+  pub slot: u64,
+  pub index: u64,
+  pub signers: HashSet<Pubkey>,
+  /// Swaps of the cycle in execution order
+  pub route: Vec<SwapTx>,
+  /// Token the cycle starts and ends in
+  pub start_token: Pubkey,
+  /// Spent by the swaps of the first leg
+  pub amount_in: u64,
+  /// Received by the swaps of the last leg
+  pub amount_out: u64,
+  /// `amount_out` minus `amount_in`, in `start_token` and before transaction fees and tips
+  pub profit: i128,
+}
@@ -0,0 +1,21 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+/// A token account whose running balance estimate after decoding a transaction differs from its
+/// post token balance, meaning a transfer was missed or misread and the reserves reported on swaps
+/// that touched it drifted.
+#[derive(Clone, Debug)]
+pub struct BalanceMismatch {
+  pub signature: Signature,
+  pub block_time: u64,
+  pub slot: u64,
+  pub index: u64,
+  pub token_account: Pubkey,
+  pub mint: Pubkey,
+  /// Running balance estimate after all decoded swaps
+  pub expected: u64,
+  /// Post token balance from the meta, 0 if the account was closed
+  pub actual: u64,
+  /// Atomic instruction index of the last swap that changed the running balance
+  pub atomic_instruction_index: u8,
+}
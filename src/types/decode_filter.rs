@@ -0,0 +1,416 @@
+use crate::types::arbitrage_event::ArbitrageEvent;
+use crate::types::pending_swap::PendingSwap;
+use crate::types::perp_event::PerpEvent;
+use crate::types::route_trade::RouteTrade;
+use solana_central::SwapDirection;
+use solana_central::SwapTx;
+use solana_central::TokenCreation;
+use solana_central::constants::TOKENS;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+
+/// Watchlist that decoding is restricted to. Empty include sets allow everything, non empty ones
+/// allow only their entries, and the categories are combined so a swap has to pass every one of
+/// them. Transactions that can't pass are skipped by `matches_tx` before any instruction is
+/// decoded. Swaps, pending swaps, token creations, arbitrages, routes and perp events are checked
+/// with the other `matches_*` methods before they are emitted. Cost and wallet deltas are only
+/// built for transactions with output left, unrecognized instructions are always emitted.
+#[derive(Default, Clone, Debug)]
+pub struct DecodeFilter {
+  pub include_mints: HashSet<Pubkey>,
+  pub exclude_mints: HashSet<Pubkey>,
+  pub include_markets: HashSet<Pubkey>,
+  pub exclude_markets: HashSet<Pubkey>,
+  /// Programs the transaction has to reference
+  pub include_programs: HashSet<Pubkey>,
+  /// Transactions referencing any of these programs are skipped
+  pub exclude_programs: HashSet<Pubkey>,
+  /// Signer wallets the transaction has to be signed by
+  pub include_wallets: HashSet<Pubkey>,
+  /// Transactions signed by any of these wallets are skipped
+  pub exclude_wallets: HashSet<Pubkey>,
+  /// Minimum lamports on the SOL side of a swap. Swaps without a SOL side are not size filtered
+  pub min_sol_amount: Option<u64>,
+}
+
+impl DecodeFilter {
+  /// Fast pre-check on the account keys, signers and token balance mints of a transaction. `false`
+  /// means no output of the transaction can pass the filter and it doesn't have to be decoded.
+  pub fn matches_tx(
+    &self,
+    account_keys: &[Pubkey],
+    num_required_signatures: u8,
+    token_balance_mints: &[Pubkey],
+  ) -> bool {
+    let signers = &account_keys[..(num_required_signatures as usize).min(account_keys.len())];
+    if signers
+      .iter()
+      .any(|signer| self.exclude_wallets.contains(signer))
+      || account_keys
+        .iter()
+        .any(|account_key| self.exclude_programs.contains(account_key))
+    {
+      return false;
+    }
+    if !self.include_wallets.is_empty()
+      && !signers
+        .iter()
+        .any(|signer| self.include_wallets.contains(signer))
+    {
+      return false;
+    }
+    if !self.include_programs.is_empty()
+      && !account_keys
+        .iter()
+        .any(|account_key| self.include_programs.contains(account_key))
+    {
+      return false;
+    }
+    if !self.include_markets.is_empty()
+      && !account_keys
+        .iter()
+        .any(|account_key| self.include_markets.contains(account_key))
+    {
+      return false;
+    }
+    // Mints are not always account keys of the swap instruction (Raydium AMMv4), but they are in
+    // the token balances of any transaction that moves them
+    if !self.include_mints.is_empty()
+      && !account_keys
+        .iter()
+        .chain(token_balance_mints)
+        .any(|mint| self.include_mints.contains(mint))
+    {
+      return false;
+    }
+    true
+  }
+
+  pub fn matches_swap(&self, swap_tx: &SwapTx) -> bool {
+    let mints = [swap_tx.token_a_address, swap_tx.token_b_address];
+    if mints.iter().any(|mint| self.exclude_mints.contains(mint))
+      || self.exclude_markets.contains(&swap_tx.market_address)
+    {
+      return false;
+    }
+    if !self.include_mints.is_empty() && !mints.iter().any(|mint| self.include_mints.contains(mint))
+    {
+      return false;
+    }
+    if !self.include_markets.is_empty() && !self.include_markets.contains(&swap_tx.market_address) {
+      return false;
+    }
+    if let Some(min_sol_amount) = self.min_sol_amount {
+      // SOL goes in on swaps towards the other token and comes out on swaps from it
+      let sol_amount = if swap_tx.token_a_address == TOKENS.wsol {
+        Some(if swap_tx.direction == SwapDirection::AToB {
+          swap_tx.swapped_amount_in
+        } else {
+          swap_tx.swapped_amount_received
+        })
+      } else if swap_tx.token_b_address == TOKENS.wsol {
+        Some(if swap_tx.direction == SwapDirection::BToA {
+          swap_tx.swapped_amount_in
+        } else {
+          swap_tx.swapped_amount_received
+        })
+      } else {
+        None
+      };
+      if sol_amount.is_some_and(|sol_amount| sol_amount < min_sol_amount) {
+        return false;
+      }
+    }
+    true
+  }
+
+  /// `matches_swap` for swaps decoded from instruction arguments, used for pending transactions and
+  /// failed swap attempts. Mints that are not part of the swap instruction (Raydium AMMv4) are
+  /// unknown and only pre-checked with the transaction, and without an outcome there is no SOL
+  /// amount for `min_sol_amount`.
+  pub fn matches_pending_swap(&self, pending_swap: &PendingSwap) -> bool {
+    let mints = [pending_swap.token_a_address, pending_swap.token_b_address];
+    let known_mints = || mints.iter().flatten();
+    if known_mints().any(|mint| self.exclude_mints.contains(mint))
+      || self.exclude_markets.contains(&pending_swap.market_address)
+    {
+      return false;
+    }
+    if !self.include_mints.is_empty()
+      && known_mints().next().is_some()
+      && !known_mints().any(|mint| self.include_mints.contains(mint))
+    {
+      return false;
+    }
+    self.include_markets.is_empty() || self.include_markets.contains(&pending_swap.market_address)
+  }
+
+  /// An arbitrage passes if any swap of its cycle does
+  pub fn matches_arbitrage(&self, arbitrage: &ArbitrageEvent) -> bool {
+    arbitrage
+      .route
+      .iter()
+      .any(|swap_tx| self.matches_swap(swap_tx))
+  }
+
+  /// A route passes if any of its child swaps among the transaction's `swaps` does. Routes without
+  /// decoded child swaps, whose hops are all on pools that aren't decoded, are checked on their
+  /// input and output mint.
+  pub fn matches_route_trade(&self, route_trade: &RouteTrade, swaps: &[SwapTx]) -> bool {
+    let mut child_swaps = swaps
+      .iter()
+      .filter(|swap_tx| route_trade.hops.contains(&swap_tx.atomic_instruction_index))
+      .peekable();
+    if child_swaps.peek().is_some() {
+      return child_swaps.any(|swap_tx| self.matches_swap(swap_tx));
+    }
+    let mints = [route_trade.input_mint, route_trade.output_mint];
+    !mints.iter().any(|mint| self.exclude_mints.contains(mint))
+      && (self.include_mints.is_empty()
+        || mints.iter().any(|mint| self.include_mints.contains(mint)))
+  }
+
+  /// Perp events are checked on their custody as the market and their owner as the wallet. They
+  /// carry no mint, so a non empty `include_mints` drops them.
+  pub fn matches_perp_event(&self, perp_event: &PerpEvent) -> bool {
+    if self.exclude_markets.contains(&perp_event.custody)
+      || perp_event
+        .owner
+        .is_some_and(|owner| self.exclude_wallets.contains(&owner))
+    {
+      return false;
+    }
+    self.include_mints.is_empty()
+      && (self.include_markets.is_empty() || self.include_markets.contains(&perp_event.custody))
+  }
+
+  pub fn matches_token_creation(&self, token_creation: &TokenCreation) -> bool {
+    if self.exclude_mints.contains(&token_creation.address) {
+      return false;
+    }
+    self.include_mints.is_empty() || self.include_mints.contains(&token_creation.address)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::types::router::Router;
+  use crate::types::swap_intent::SwapIntent;
+  use crate::types::swap_mode::SwapMode;
+  use crate::utilities::test_swap_tx_builder::TestSwapTxBuilder;
+  use solana_central::Pools;
+  use solana_sdk::signature::Signature;
+
+  fn pending_swap(token_a_address: Option<Pubkey>, market_address: Pubkey) -> PendingSwap {
+    PendingSwap {
+      pool: Pools::RaydiumAmmV4,
+      direction: None,
+      market_address,
+      token_a_address,
+      token_b_address: token_a_address.map(|_| TOKENS.wsol),
+      intent: SwapIntent {
+        mode: SwapMode::ExactIn,
+        specified_amount: 100,
+        limit_amount: 90,
+        slippage_tolerance_bps: None,
+      },
+      slot: 1,
+      instruction_index: 0,
+      signature: Signature::new_unique(),
+      signers: HashSet::new(),
+    }
+  }
+
+  #[test]
+  fn empty_filter_matches_everything() {
+    let filter = DecodeFilter::default();
+    let account_keys = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+    assert!(filter.matches_tx(&account_keys, 1, &[]));
+    assert!(filter.matches_swap(&TestSwapTxBuilder::default().build()));
+    assert!(filter.matches_pending_swap(&pending_swap(None, Pubkey::new_unique())));
+  }
+
+  #[test]
+  fn matches_tx_checks_signers_programs_and_mints() {
+    let signer = Pubkey::new_unique();
+    let program = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let account_keys = vec![signer, program];
+
+    let mut filter = DecodeFilter::default();
+    filter.exclude_wallets.insert(signer);
+    assert!(!filter.matches_tx(&account_keys, 1, &[]));
+    // Only the first `num_required_signatures` account keys are signers
+    let reversed = vec![program, signer];
+    assert!(filter.matches_tx(&reversed, 1, &[]));
+
+    let mut filter = DecodeFilter::default();
+    filter.exclude_programs.insert(program);
+    assert!(!filter.matches_tx(&account_keys, 1, &[]));
+
+    let mut filter = DecodeFilter::default();
+    filter.include_programs.insert(Pubkey::new_unique());
+    assert!(!filter.matches_tx(&account_keys, 1, &[]));
+    filter.include_programs.insert(program);
+    assert!(filter.matches_tx(&account_keys, 1, &[]));
+
+    // Mints can be found in the token balances only
+    let mut filter = DecodeFilter::default();
+    filter.include_mints.insert(mint);
+    assert!(!filter.matches_tx(&account_keys, 1, &[]));
+    assert!(filter.matches_tx(&account_keys, 1, &[mint]));
+  }
+
+  #[test]
+  fn matches_swap_checks_mints_and_markets() {
+    let mint = Pubkey::new_unique();
+    let market = Pubkey::new_unique();
+    let swap = TestSwapTxBuilder::default()
+      .market(market)
+      .tokens(mint, TOKENS.wsol)
+      .build();
+
+    let mut filter = DecodeFilter::default();
+    filter.include_mints.insert(mint);
+    assert!(filter.matches_swap(&swap));
+    filter.exclude_markets.insert(market);
+    assert!(!filter.matches_swap(&swap));
+
+    let mut filter = DecodeFilter::default();
+    filter.include_markets.insert(Pubkey::new_unique());
+    assert!(!filter.matches_swap(&swap));
+
+    let mut filter = DecodeFilter::default();
+    filter.exclude_mints.insert(TOKENS.wsol);
+    assert!(!filter.matches_swap(&swap));
+  }
+
+  #[test]
+  fn min_sol_amount_uses_the_sol_side() {
+    let filter = DecodeFilter {
+      min_sol_amount: Some(500),
+      ..Default::default()
+    };
+    // Token B is SOL, so SOL goes in on BToA and comes out on AToB
+    let mint = Pubkey::new_unique();
+    let swap = |direction: SwapDirection, amount_in: u64, received: u64| {
+      TestSwapTxBuilder::default()
+        .tokens(mint, TOKENS.wsol)
+        .direction(direction)
+        .amounts(amount_in, received)
+        .build()
+    };
+    assert!(filter.matches_swap(&swap(SwapDirection::BToA, 500, 1_000)));
+    assert!(!filter.matches_swap(&swap(SwapDirection::BToA, 499, 1_000)));
+    assert!(filter.matches_swap(&swap(SwapDirection::AToB, 1_000, 500)));
+    assert!(!filter.matches_swap(&swap(SwapDirection::AToB, 1_000, 499)));
+
+    // Swaps without a SOL side are not size filtered
+    let token_swap = TestSwapTxBuilder::default().amounts(1, 1).build();
+    assert!(filter.matches_swap(&token_swap));
+  }
+
+  #[test]
+  fn matches_pending_swap_keeps_unknown_mints() {
+    let mint = Pubkey::new_unique();
+    let market = Pubkey::new_unique();
+    let mut filter = DecodeFilter::default();
+    filter.include_mints.insert(mint);
+    assert!(filter.matches_pending_swap(&pending_swap(Some(mint), market)));
+    assert!(!filter.matches_pending_swap(&pending_swap(Some(Pubkey::new_unique()), market)));
+    assert!(filter.matches_pending_swap(&pending_swap(None, market)));
+
+    filter.exclude_mints.insert(mint);
+    assert!(!filter.matches_pending_swap(&pending_swap(Some(mint), market)));
+
+    let mut filter = DecodeFilter::default();
+    filter.include_markets.insert(market);
+    assert!(filter.matches_pending_swap(&pending_swap(None, market)));
+    assert!(!filter.matches_pending_swap(&pending_swap(None, Pubkey::new_unique())));
+  }
+
+  #[test]
+  fn routes_and_arbitrages_pass_if_any_swap_does() {
+    let (mint, market) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut watched = TestSwapTxBuilder::default().market(market).build();
+    watched.atomic_instruction_index = 1;
+    let mut other = TestSwapTxBuilder::default().build();
+    other.atomic_instruction_index = 2;
+    let swaps = [watched.clone(), other.clone()];
+    let route_trade = |hops: Vec<u8>| RouteTrade {
+      router: Router::JupiterV6,
+      signature: Signature::new_unique(),
+      block_time: 0,
+      slot: 1,
+      index: 0,
+      instruction_index: 0,
+      signers: HashSet::new(),
+      input_mint: mint,
+      output_mint: TOKENS.wsol,
+      amount_in: 100,
+      amount_out: 90,
+      intent: None,
+      slippage_bps: None,
+      platform_fee_bps: None,
+      platform_fee_amount: 0,
+      platform_fee_mint: None,
+      hops,
+    };
+    let arbitrage = |route: Vec<SwapTx>| ArbitrageEvent {
+      signature: Signature::new_unique(),
+      block_time: 0,
+      slot: 1,
+      index: 0,
+      signers: HashSet::new(),
+      route,
+      start_token: TOKENS.wsol,
+      amount_in: 100,
+      amount_out: 110,
+      profit: 10,
+    };
+
+    let mut filter = DecodeFilter::default();
+    filter.include_markets.insert(market);
+    assert!(filter.matches_route_trade(&route_trade(vec![1, 2]), &swaps));
+    assert!(!filter.matches_route_trade(&route_trade(vec![2]), &swaps));
+    assert!(filter.matches_arbitrage(&arbitrage(vec![other.clone(), watched])));
+    assert!(!filter.matches_arbitrage(&arbitrage(vec![other])));
+
+    // Routes without decoded child swaps are checked on their mints
+    let mut filter = DecodeFilter::default();
+    filter.include_mints.insert(mint);
+    assert!(filter.matches_route_trade(&route_trade(Vec::new()), &swaps));
+    filter.exclude_mints.insert(TOKENS.wsol);
+    assert!(!filter.matches_route_trade(&route_trade(Vec::new()), &swaps));
+  }
+
+  #[test]
+  fn matches_token_creation_checks_the_mint() {
+    let mint = Pubkey::new_unique();
+    let creation = TokenCreation {
+      address: mint,
+      creator: Pubkey::new_unique(),
+      market_address: Pubkey::new_unique(),
+      name: String::new(),
+      symbol: String::new(),
+      uri: String::new(),
+      description: String::new(),
+      twitter: String::new(),
+      website: String::new(),
+      block_time: 0,
+      slot: 1,
+      index: 0,
+      atomic_instruction_index: 0,
+      signature: Signature::new_unique(),
+    };
+    let mut filter = DecodeFilter::default();
+    assert!(filter.matches_token_creation(&creation));
+    filter.include_mints.insert(Pubkey::new_unique());
+    assert!(!filter.matches_token_creation(&creation));
+    filter.include_mints.insert(mint);
+    assert!(filter.matches_token_creation(&creation));
+    filter.exclude_mints.insert(mint);
+    assert!(!filter.matches_token_creation(&creation));
+  }
+}
@@ -0,0 +1,27 @@
+use crate::address_lookup_table::address_lookup_table_resolver::AddressLookupTableResolver;
+use crate::types::decode_filter::DecodeFilter;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+
+/// Optional behaviour for the decoding entry points. `DecodeOptions::default()` decodes the same
+/// way the library always has.
+#[derive(Default, Clone, Copy)]
+pub struct DecodeOptions<'a> {
+  /// Used to reconstruct account keys of v0 transactions when loaded addresses are not available
+  /// from status meta. Transactions that need it but can't be resolved are skipped.
+  pub address_lookup_table_resolver: Option<&'a dyn AddressLookupTableResolver>,
+  /// Decode failed transactions into `FailedSwapAttempt`s instead of skipping them
+  pub decode_failed_txs: bool,
+  /// Accounts SOL transfers to count as tips in addition to `JITO_TIP_ACCOUNTS`, e.g. Nozomi, 0slot
+  /// or bloXroute tip accounts
+  pub tip_accounts: Option<&'a HashSet<Pubkey>>,
+  /// Check the running token balances against the post token balances once a transaction is
+  /// decoded and emit a `BalanceMismatch` for every account that disagrees
+  pub reconcile_token_balances: bool,
+  /// Parse "Program data:" events from program logs and decode the ones not available as self CPI
+  /// events, reporting truncated logs as `LogTruncation`s
+  pub decode_log_events: bool,
+  /// Only decode transactions and emit swaps and token creations that pass this watchlist. `None`
+  /// decodes everything
+  pub filter: Option<&'a DecodeFilter>,
+}
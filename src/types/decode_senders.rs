@@ -0,0 +1,58 @@
+use crate::types::arbitrage_event::ArbitrageEvent;
+use crate::types::balance_mismatch::BalanceMismatch;
+use crate::types::failed_swap_attempt::FailedSwapAttempt;
+use crate::types::log_truncation::LogTruncation;
+use crate::types::pending_swap::PendingSwap;
+use crate::types::perp_event::PerpEvent;
+use crate::types::route_trade::RouteTrade;
+use crate::types::slot_rollback::SlotRollback;
+use crate::types::swap_details::SwapDetails;
+use crate::types::tx_cost::TxCost;
+use crate::types::unrecognized_instruction::UnrecognizedInstruction;
+use crate::types::wallet_delta::WalletDelta;
+use solana_central::SwapTx;
+use solana_central::TokenCreation;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::Sender;
+
+/// Broadcast channel senders that decoded output is written to. Consumers subscribe to the
+/// channels for the output types they care about, sends to channels without receivers are dropped.
+#[derive(Clone)]
+pub struct DecodeSenders {
+  pub swap_tx_sender: Sender<SwapTx>,
+  pub token_create_sender: Sender<TokenCreation>,
+  pub swap_details_sender: Sender<SwapDetails>,
+  pub pending_swap_sender: Sender<PendingSwap>,
+  pub failed_swap_attempt_sender: Sender<FailedSwapAttempt>,
+  pub tx_cost_sender: Sender<TxCost>,
+  pub arbitrage_sender: Sender<ArbitrageEvent>,
+  pub route_trade_sender: Sender<RouteTrade>,
+  pub perp_event_sender: Sender<PerpEvent>,
+  pub wallet_delta_sender: Sender<WalletDelta>,
+  pub balance_mismatch_sender: Sender<BalanceMismatch>,
+  pub unrecognized_instruction_sender: Sender<UnrecognizedInstruction>,
+  pub log_truncation_sender: Sender<LogTruncation>,
+  pub slot_rollback_sender: Sender<SlotRollback>,
+}
+
+impl DecodeSenders {
+  /// Create a new channel for every output type, each with the given capacity.
+  pub fn new(capacity: usize) -> Self {
+    DecodeSenders {
+      swap_tx_sender: broadcast::channel(capacity).0,
+      token_create_sender: broadcast::channel(capacity).0,
+      swap_details_sender: broadcast::channel(capacity).0,
+      pending_swap_sender: broadcast::channel(capacity).0,
+      failed_swap_attempt_sender: broadcast::channel(capacity).0,
+      tx_cost_sender: broadcast::channel(capacity).0,
+      arbitrage_sender: broadcast::channel(capacity).0,
+      route_trade_sender: broadcast::channel(capacity).0,
+      perp_event_sender: broadcast::channel(capacity).0,
+      wallet_delta_sender: broadcast::channel(capacity).0,
+      balance_mismatch_sender: broadcast::channel(capacity).0,
+      unrecognized_instruction_sender: broadcast::channel(capacity).0,
+      log_truncation_sender: broadcast::channel(capacity).0,
+      slot_rollback_sender: broadcast::channel(capacity).0,
+    }
+  }
+}
@@ -0,0 +1,95 @@
+use crate::instruction::process_unrecognized_instruction::process_unrecognized_instruction;
+use crate::types::arbitrage_event::ArbitrageEvent;
+use crate::types::balance_mismatch::BalanceMismatch;
+use crate::types::failed_swap_attempt::FailedSwapAttempt;
+use crate::types::log_truncation::LogTruncation;
+use crate::types::pending_swap::PendingSwap;
+use crate::types::perp_event::PerpEvent;
+use crate::types::route_trade::RouteTrade;
+use crate::types::swap_details::SwapDetails;
+use crate::types::tx_cost::TxCost;
+use crate::types::unrecognized_instruction::UnrecognizedInstruction;
+use crate::types::wallet_delta::WalletDelta;
+use solana_central::Instruction;
+use solana_central::SwapTx;
+use solana_central::TokenCreation;
+use solana_sdk::signature::Signature;
+
+/// All output decoded from a single transaction. The instruction loops push into this while
+/// walking the transaction and it is written out to channels once decoding is finished. `swaps`
+/// and `swap_details` are parallel, the details at an index belong to the swap at the same index.
+#[derive(Default)]
+pub struct DecodedTx {
+  pub swaps: Vec<SwapTx>,
+  pub swap_details: Vec<SwapDetails>,
+  pub token_creations: Vec<TokenCreation>,
+  /// Swaps from transactions analyzed without status meta
+  pub pending_swaps: Vec<PendingSwap>,
+  /// Swaps from failed transactions, only filled when `decode_failed_txs` is set
+  pub failed_swap_attempts: Vec<FailedSwapAttempt>,
+  /// `None` for transactions analyzed without status meta
+  pub tx_cost: Option<TxCost>,
+  /// Cycles in `swaps`, filled once all instructions are decoded
+  pub arbitrages: Vec<ArbitrageEvent>,
+  pub route_trades: Vec<RouteTrade>,
+  pub perp_events: Vec<PerpEvent>,
+  pub wallet_deltas: Vec<WalletDelta>,
+  /// Only filled when `reconcile_token_balances` is set
+  pub balance_mismatches: Vec<BalanceMismatch>,
+  /// Instructions of supported programs that could not be decoded
+  pub unrecognized_instructions: Vec<UnrecognizedInstruction>,
+  /// Only filled when `decode_log_events` is set
+  pub log_truncations: Vec<LogTruncation>,
+}
+
+impl DecodedTx {
+  /// Push an instruction a decoder could not handle as an `UnrecognizedInstruction`
+  pub fn push_unrecognized(
+    &mut self,
+    instruction: &Instruction,
+    block_time: u64,
+    slot: u64,
+    index: u64,
+    atomic_instruction_index: u8,
+    signature: &Signature,
+  ) {
+    self
+      .unrecognized_instructions
+      .push(process_unrecognized_instruction(
+        instruction,
+        block_time,
+        slot,
+        index,
+        atomic_instruction_index,
+        signature,
+      ));
+  }
+
+  /// Push a decoded swap with its details, or `instruction` as an `UnrecognizedInstruction` if the
+  /// decoder returned `None`
+  pub fn push_swap_or_unrecognized(
+    &mut self,
+    swap: Option<(SwapTx, SwapDetails)>,
+    instruction: &Instruction,
+    block_time: u64,
+    slot: u64,
+    index: u64,
+    atomic_instruction_index: u8,
+    signature: &Signature,
+  ) {
+    match swap {
+      Some((swap_tx, swap_details)) => {
+        self.swaps.push(swap_tx);
+        self.swap_details.push(swap_details);
+      }
+      None => self.push_unrecognized(
+        instruction,
+        block_time,
+        slot,
+        index,
+        atomic_instruction_index,
+        signature,
+      ),
+    }
+  }
+}
@@ -0,0 +1,52 @@
+use crate::constants::jupiter_perps::JUPITER_PERPS_PROGRAM_ID;
+use crate::constants::router_programs::DFLOW_ROUTER_PROGRAM_ID;
+use crate::constants::router_programs::JUPITER_V6_PROGRAM_ID;
+use crate::constants::router_programs::OKX_ROUTER_PROGRAM_ID;
+use crate::constants::router_programs::TITAN_ROUTER_PROGRAM_ID;
+use solana_central::constants::PUMP_CONSTANTS;
+use solana_central::constants::RAYDIUM_CONSTANTS;
+use solana_sdk::pubkey::Pubkey;
+
+/// Protocol decoders of this crate, used to derive stream subscriptions that match what is decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Decoder {
+  RaydiumAmmV4,
+  RaydiumCpmm,
+  RaydiumLaunchpad,
+  Pumpswap,
+  /// Bonding curve swaps and token creations
+  Pumpfun,
+  JupiterPerps,
+  /// Aggregator routes (Jupiter v6, OKX, DFlow, Titan)
+  Routers,
+}
+
+impl Decoder {
+  pub const ALL: [Decoder; 7] = [
+    Decoder::RaydiumAmmV4,
+    Decoder::RaydiumCpmm,
+    Decoder::RaydiumLaunchpad,
+    Decoder::Pumpswap,
+    Decoder::Pumpfun,
+    Decoder::JupiterPerps,
+    Decoder::Routers,
+  ];
+
+  /// Program ids a transaction has to reference for the decoder to produce output from it
+  pub fn program_ids(&self) -> Vec<Pubkey> {
+    match self {
+      Decoder::RaydiumAmmV4 => vec![RAYDIUM_CONSTANTS.amm_program],
+      Decoder::RaydiumCpmm => vec![RAYDIUM_CONSTANTS.cpmm_program],
+      Decoder::RaydiumLaunchpad => vec![RAYDIUM_CONSTANTS.launchpad_program],
+      Decoder::Pumpswap => vec![PUMP_CONSTANTS.pump_swap_program],
+      Decoder::Pumpfun => vec![PUMP_CONSTANTS.bonding_curve_program],
+      Decoder::JupiterPerps => vec![JUPITER_PERPS_PROGRAM_ID],
+      Decoder::Routers => vec![
+        JUPITER_V6_PROGRAM_ID,
+        OKX_ROUTER_PROGRAM_ID,
+        DFLOW_ROUTER_PROGRAM_ID,
+        TITAN_ROUTER_PROGRAM_ID,
+      ],
+    }
+  }
+}
@@ -0,0 +1,11 @@
+/// Known layouts of protocol events that decoders pick between. Each variant is decoded as a
+/// prefix of the event data, bytes after it are kept as unknown tail data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventLayout {
+  /// Pumpfun `TradeEvent` before creator fees
+  PfTradeEventOld,
+  /// Pumpfun `TradeEvent` with creator fees and volume tracking
+  PfTradeEventCurrent,
+  PumpswapBuyEvent,
+  PumpswapSellEvent,
+}
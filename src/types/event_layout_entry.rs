@@ -0,0 +1,20 @@
+use crate::types::event_layout::EventLayout;
+use solana_sdk::pubkey::Pubkey;
+use std::ops::RangeInclusive;
+
+/// One entry of an event layout registry. An event matches the entry when it is emitted by
+/// `program_id`, its data starts with `discriminator`, its length is at least `min_len` and below
+/// `max_len`, and its slot is within `slots`.
+#[derive(Clone, Debug)]
+pub struct EventLayoutEntry {
+  pub program_id: Pubkey,
+  /// Event CPI tag followed by the event discriminator
+  pub discriminator: [u8; 16],
+  pub min_len: usize,
+  /// Exclusive, `None` for no upper bound so longer events with new trailing fields still match
+  pub max_len: Option<usize>,
+  /// Slots the layout was live for, `None` for any slot. Used to keep historical layouts correct
+  /// for backfills when a length alone is ambiguous
+  pub slots: Option<RangeInclusive<u64>>,
+  pub layout: EventLayout,
+}
@@ -0,0 +1,20 @@
+use crate::types::pending_swap::PendingSwap;
+use crate::types::swap_failure_kind::SwapFailureKind;
+use solana_sdk::transaction::TransactionError;
+
+/// A swap in a failed transaction. The swap is decoded from instruction arguments only, the same
+/// way as for unconfirmed transactions, since a failed transaction has no swap outcome.
+#[derive(Clone, Debug)]
+pub struct FailedSwapAttempt {
+  pub swap: PendingSwap,
+  pub block_time: u64,
+  pub index: u64,
+  pub error: TransactionError,
+  /// Top level instruction the transaction failed in. `None` for errors not tied to an instruction
+  pub failed_instruction_index: Option<u8>,
+  /// Program error code if the transaction failed with a custom program error
+  pub custom_error_code: Option<u32>,
+  /// Why the swap failed. Only specific if the swap is in the failed instruction, swaps in other
+  /// instructions of the transaction are `Other`
+  pub failure_kind: SwapFailureKind,
+}
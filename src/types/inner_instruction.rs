@@ -0,0 +1,12 @@
+use solana_central::Instruction;
+
+/// Inner instruction normalized across transaction sources, together with the top level
+/// instruction that yielded it.
+pub struct InnerInstruction<'a> {
+  /// Index of the top level instruction this inner instruction was yielded by
+  pub top_level_index: u8,
+  pub instruction: Instruction<'a>,
+  /// Invocation depth, 1 is top level so inner instructions start at 2. `None` for transactions
+  /// from before stack heights were recorded
+  pub stack_height: Option<u32>,
+}
@@ -28,11 +28,13 @@ pub enum InstructionType {
   PumpswapRemoveLiquidity,
 
   // TODO Add CLMM support
-  // TODO add Jupiter Perps support
   // TODO add private AMMs support
 
   PfBondingCurveCreate,
 
+  // Perps
+  JupiterPerpsEvent,
+
   // None of the above
   None,
 
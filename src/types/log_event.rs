@@ -0,0 +1,16 @@
+use solana_sdk::pubkey::Pubkey;
+
+/// A "Program data:" payload from the program logs, attributed to the invocation that logged it.
+#[derive(Clone, Debug)]
+pub struct LogEvent {
+  /// Top level instruction the emitting invocation belongs to
+  pub top_level_index: u8,
+  /// Index of the emitting invocation in the inner instructions of the top level instruction,
+  /// `None` if the top level instruction itself emitted the event
+  pub inner_index: Option<u8>,
+  pub program_id: Pubkey,
+  /// Invoke depth of the emitting invocation, 1 for top level instructions
+  pub invoke_depth: u8,
+  /// Base64 decoded payload, multiple `sol_log_data` fields are concatenated
+  pub data: Vec<u8>,
+}
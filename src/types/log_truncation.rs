@@ -0,0 +1,13 @@
+use solana_sdk::signature::Signature;
+
+/// Reported for transactions whose program logs were truncated while log events were being
+/// decoded, meaning log events after the truncation point were lost.
+#[derive(Clone, Debug)]
+pub struct LogTruncation {
+  pub signature: Signature,
+  pub block_time: u64,
+  pub slot: u64,
+  pub index: u64,
+  /// Number of log events parsed before the truncation
+  pub log_events_parsed: usize,
+}
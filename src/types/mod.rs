@@ -1,5 +1,43 @@
 pub mod instruction_type;
 pub mod tx_format;
 pub mod archive_tx;
+pub mod arbitrage_event;
+pub mod balance_mismatch;
 pub mod grpc_tx;
-pub mod json_rpc_tx;
\ No newline at end of file
+pub mod json_rpc_tx;
+pub mod log_event;
+pub mod log_truncation;
+pub mod raw_tx;
+pub mod decode_filter;
+pub mod decode_options;
+pub mod decode_senders;
+pub mod decoded_tx;
+pub mod decoder;
+pub mod event_layout;
+pub mod event_layout_entry;
+pub mod failed_swap_attempt;
+pub mod inner_instruction;
+pub mod pending_swap;
+pub mod perp_event;
+pub mod perp_event_kind;
+pub mod perp_side;
+pub mod probable_bundle;
+pub mod program_logs;
+pub mod route_trade;
+pub mod router;
+pub mod running_token_balances;
+pub mod sandwich_event;
+pub mod sandwich_victim;
+pub mod slot_rollback;
+pub mod slot_rollback_reason;
+pub mod swap_details;
+pub mod swap_failure_kind;
+pub mod swap_fees;
+pub mod swap_intent;
+pub mod swap_mode;
+pub mod tip;
+pub mod token_balance;
+pub mod token_transfer;
+pub mod tx_cost;
+pub mod unrecognized_instruction;
+pub mod wallet_delta;
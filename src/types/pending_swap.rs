@@ -0,0 +1,29 @@
+use crate::types::swap_intent::SwapIntent;
+use solana_central::Pools;
+use solana_central::SwapDirection;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashSet;
+
+/// A swap decoded from an unconfirmed transaction that has no status meta. Only what the user
+/// asked for is known: the pool, direction and the swap arguments in `intent`. Outcome amounts,
+/// vault balances and prices are unknown until the transaction lands and are deliberately not part
+/// of this type.
+#[derive(Clone, Debug)]
+pub struct PendingSwap {
+  pub pool: Pools,
+  /// `None` if the direction can't be derived without token balances (Raydium AMMv4)
+  pub direction: Option<SwapDirection>,
+  pub market_address: Pubkey,
+  /// `None` if the mint is not part of the swap instruction accounts (Raydium AMMv4)
+  pub token_a_address: Option<Pubkey>,
+  pub token_b_address: Option<Pubkey>,
+  /// Swap arguments, `slippage_tolerance_bps` is always `None` since there is no outcome
+  pub intent: SwapIntent,
+  /// Slot the transaction was seen in, not necessarily the slot it lands in
+  pub slot: u64,
+  /// Index of the top level instruction the swap is in
+  pub instruction_index: u8,
+  pub signature: Signature,
+  pub signers: HashSet<Pubkey>,
+}
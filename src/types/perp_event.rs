@@ -0,0 +1,34 @@
+use crate::types::perp_event_kind::PerpEventKind;
+use crate::types::perp_side::PerpSide;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+/// A Jupiter Perps position change decoded from the program's self CPI events. USD amounts have 6
+/// decimals.
+#[derive(Clone, Debug)]
+pub struct PerpEvent {
+  pub kind: PerpEventKind,
+  pub signature: Signature,
+  pub block_time: u64,
+  pub slot: u64,
+  pub index: u64,
+  pub atomic_instruction_index: u8,
+  pub position: Pubkey,
+  /// `None` for liquidations, whose event doesn't carry the owner
+  pub owner: Option<Pubkey>,
+  /// Custody of the traded asset
+  pub custody: Pubkey,
+  pub collateral_custody: Pubkey,
+  pub side: PerpSide,
+  /// Position size after the event
+  pub size_usd: u64,
+  pub size_usd_delta: u64,
+  /// `None` for liquidations
+  pub collateral_usd_delta: Option<u64>,
+  /// Execution price
+  pub price: u64,
+  /// All fees charged, including the liquidation fee for liquidations
+  pub fee_usd: u64,
+  /// Realized profit (positive) or loss (negative). `None` when increasing a position
+  pub pnl_usd: Option<i128>,
+}
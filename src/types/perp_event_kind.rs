@@ -0,0 +1,10 @@
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum PerpEventKind {
+  /// Keeper fulfilled an increase position request
+  IncreasePosition,
+  /// Keeper fulfilled a decrease position request
+  DecreasePosition,
+  LiquidateFullPosition,
+  InstantIncreasePosition,
+  InstantDecreasePosition,
+}
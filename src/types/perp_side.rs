@@ -0,0 +1,5 @@
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum PerpSide {
+  Long,
+  Short,
+}
@@ -0,0 +1,16 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+/// Consecutive transactions in a block that were likely landed together as a bundle, found by
+/// `group_probable_bundles`. Bundle membership is not recorded on chain so this is a heuristic.
+#[derive(Clone, Debug)]
+pub struct ProbableBundle {
+  pub slot: u64,
+  /// Index in block of the first transaction
+  pub start_index: u64,
+  /// Transactions in block order
+  pub signatures: Vec<Signature>,
+  pub tip_payer: Pubkey,
+  /// Sum of all tips paid by transactions in the bundle
+  pub tip_lamports: u64,
+}
@@ -0,0 +1,9 @@
+use crate::types::log_event::LogEvent;
+
+/// Events parsed from the program logs of a transaction.
+#[derive(Clone, Debug, Default)]
+pub struct ProgramLogs {
+  pub events: Vec<LogEvent>,
+  /// The validator hit its log limit and stopped recording, events after that point are missing
+  pub truncated: bool,
+}
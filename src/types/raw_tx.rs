@@ -0,0 +1,18 @@
+use solana_storage_proto::convert::generated::TransactionStatusMeta as StorageTransactionStatusMeta;
+use yellowstone_grpc_proto::solana::storage::confirmed_block::TransactionStatusMeta as YellowstoneTransactionStatusMeta;
+
+/// Status meta stored alongside raw transaction bytes, either decoded from the Solana storage
+/// protobuf or from the Yellowstone protobuf.
+#[derive(Clone, Copy)]
+pub enum RawTxMeta<'a> {
+  Storage(&'a StorageTransactionStatusMeta),
+  Yellowstone(&'a YellowstoneTransactionStatusMeta),
+}
+
+/// Transaction wrapper for transactions stored in wire format. Contains the bincode serialized
+/// `VersionedTransaction` bytes and its metadata. The bytes are only deserialized when the
+/// transaction is analyzed and did not fail.
+pub struct RawTx<'a> {
+  pub tx_bytes: &'a [u8],
+  pub meta: RawTxMeta<'a>,
+}
@@ -0,0 +1,35 @@
+use crate::types::router::Router;
+use crate::types::swap_intent::SwapIntent;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashSet;
+
+/// The user level trade of an aggregator route, as opposed to the per hop `SwapTx`s it is made
+/// of. Hops on pools this library decodes are linked by `hops`, hops on other pools only count
+/// towards the amounts if the router reports them (Jupiter `SwapEvent`).
+#[derive(Clone, Debug)]
+pub struct RouteTrade {
+  pub router: Router,
+  pub signature: Signature,
+  pub block_time: u64,
+  pub slot: u64,
+  pub index: u64,
+  /// Top level instruction of the route
+  pub instruction_index: u8,
+  pub signers: HashSet<Pubkey>,
+  pub input_mint: Pubkey,
+  pub output_mint: Pubkey,
+  pub amount_in: u64,
+  pub amount_out: u64,
+  /// Route arguments, `None` for routers whose arguments aren't decoded
+  pub intent: Option<SwapIntent>,
+  /// Slippage the user allowed against the quote, `None` for routers whose arguments aren't
+  /// decoded
+  pub slippage_bps: Option<u16>,
+  pub platform_fee_bps: Option<u8>,
+  /// Platform fee charged, 0 if none was charged or the router doesn't report it
+  pub platform_fee_amount: u64,
+  pub platform_fee_mint: Option<Pubkey>,
+  /// Atomic instruction indexes of the child `SwapTx`s of the route
+  pub hops: Vec<u8>,
+}
@@ -0,0 +1,8 @@
+/// Swap aggregators whose top level instructions are decoded into `RouteTrade`s
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Router {
+  JupiterV6,
+  Okx,
+  DFlow,
+  Titan,
+}
@@ -0,0 +1,203 @@
+use crate::types::token_transfer::TokenTransfer;
+use std::collections::HashMap;
+use std::ops::Index;
+
+/// Token account balances estimated while walking a transaction, keyed by the token account
+/// index in the transaction account keys. Starts from the pre token balances and is updated with
+/// the transfer amounts of decoded swaps, so pool reserves are known after each swap. Remembers
+/// which accounts were changed so the final estimates can be reconciled with the post token
+/// balances.
+#[derive(Default, Clone, Debug)]
+pub struct RunningTokenBalances {
+  balances: HashMap<u8, u64>,
+  /// Token account index to the atomic instruction index of the last swap that changed it
+  touched: HashMap<u8, u8>,
+  post_balances: HashMap<u8, u64>,
+  /// Token account index to the number of token transfers in the transaction that move its funds
+  transfer_counts: HashMap<u8, u32>,
+}
+
+impl RunningTokenBalances {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Set the starting balance of a token account
+  pub fn insert(&mut self, account_index: u8, amount: u64) {
+    self.balances.insert(account_index, amount);
+  }
+
+  /// Set the balance of a token account after the transaction
+  pub fn insert_post(&mut self, account_index: u8, amount: u64) {
+    self.post_balances.insert(account_index, amount);
+  }
+
+  /// Count a token transfer of the transaction against its source and destination accounts
+  pub fn count_transfer(&mut self, transfer: &TokenTransfer) {
+    *self.transfer_counts.entry(transfer.source).or_insert(0) += 1;
+    *self
+      .transfer_counts
+      .entry(transfer.destination)
+      .or_insert(0) += 1;
+  }
+
+  /// Amount a transfer actually credits to its destination. Uses the fee from
+  /// `TransferCheckedWithFee` when present. Token-2022 mints with a transfer fee also withhold it
+  /// on plain `TransferChecked`, so when the destination is moved by no other transfer in the
+  /// transaction its post balance delta is used instead of the instruction amount.
+  pub fn received_amount(&self, transfer: &TokenTransfer) -> u64 {
+    if transfer.fee.is_some() || !transfer.is_token_2022 {
+      return transfer.amount_after_fee();
+    }
+    if self.transfer_counts.get(&transfer.destination) != Some(&1) {
+      return transfer.amount;
+    }
+    let (Some(balance), Some(post_balance)) = (
+      self.balances.get(&transfer.destination),
+      self.post_balances.get(&transfer.destination),
+    ) else {
+      return transfer.amount;
+    };
+    match post_balance.checked_sub(*balance) {
+      Some(delta) if delta < transfer.amount => delta,
+      _ => transfer.amount,
+    }
+  }
+
+  pub fn get(&self, account_index: u8) -> Option<u64> {
+    self.balances.get(&account_index).copied()
+  }
+
+  /// Balance of a token account after the transaction. `None` if the account has no post token
+  /// balance, e.g. because it was closed
+  pub fn get_post(&self, account_index: u8) -> Option<u64> {
+    self.post_balances.get(&account_index).copied()
+  }
+
+  /// Add to the balance of a token account if it is tracked
+  pub fn credit(&mut self, account_index: u8, amount: u64, atomic_instruction_index: u8) {
+    if let Some(balance) = self.balances.get_mut(&account_index) {
+      *balance = balance.saturating_add(amount);
+      self.touched.insert(account_index, atomic_instruction_index);
+    }
+  }
+
+  /// Subtract from the balance of a token account if it is tracked. A missed transfer can make
+  /// the estimate lower than the debit, the balance is floored at 0 instead of underflowing and
+  /// the drift shows up when reconciling.
+  pub fn debit(&mut self, account_index: u8, amount: u64, atomic_instruction_index: u8) {
+    if let Some(balance) = self.balances.get_mut(&account_index) {
+      *balance = balance.saturating_sub(amount);
+      self.touched.insert(account_index, atomic_instruction_index);
+    }
+  }
+
+  /// Token accounts changed by decoded swaps, with the atomic instruction index of the last swap
+  /// that changed each
+  pub fn touched(&self) -> impl Iterator<Item = (u8, u8)> + '_ {
+    self
+      .touched
+      .iter()
+      .map(|(account_index, atomic_instruction_index)| (*account_index, *atomic_instruction_index))
+  }
+}
+
+impl Index<&u8> for RunningTokenBalances {
+  type Output = u64;
+
+  fn index(&self, account_index: &u8) -> &u64 {
+    &self.balances[account_index]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn transfer(
+    destination: u8,
+    amount: u64,
+    fee: Option<u64>,
+    is_token_2022: bool,
+  ) -> TokenTransfer {
+    TokenTransfer {
+      source: 1,
+      destination,
+      amount,
+      fee,
+      is_token_2022,
+    }
+  }
+
+  #[test]
+  fn received_amount_uses_instruction_fee() {
+    let running_token_balances = RunningTokenBalances::new();
+    assert_eq!(
+      running_token_balances.received_amount(&transfer(2, 1_000, None, false)),
+      1_000
+    );
+    assert_eq!(
+      running_token_balances.received_amount(&transfer(2, 1_000, Some(10), true)),
+      990
+    );
+    assert_eq!(
+      running_token_balances.received_amount(&transfer(2, 1_000, Some(2_000), true)),
+      0
+    );
+  }
+
+  #[test]
+  fn received_amount_uses_post_balance_delta_for_token_2022() {
+    let token_transfer = transfer(2, 1_000, None, true);
+    let mut running_token_balances = RunningTokenBalances::new();
+    running_token_balances.insert(2, 5_000);
+    running_token_balances.insert_post(2, 5_990);
+    running_token_balances.count_transfer(&token_transfer);
+    assert_eq!(running_token_balances.received_amount(&token_transfer), 990);
+
+    // The delta can't be attributed when another transfer moves the destination's funds
+    running_token_balances.count_transfer(&transfer(3, 1, None, true));
+    running_token_balances.count_transfer(&TokenTransfer {
+      source: 2,
+      ..transfer(4, 1, None, true)
+    });
+    assert_eq!(
+      running_token_balances.received_amount(&token_transfer),
+      1_000
+    );
+  }
+
+  #[test]
+  fn received_amount_falls_back_to_transfer_amount() {
+    let token_transfer = transfer(2, 1_000, None, true);
+    let mut running_token_balances = RunningTokenBalances::new();
+    running_token_balances.count_transfer(&token_transfer);
+    // No pre or post balance
+    assert_eq!(
+      running_token_balances.received_amount(&token_transfer),
+      1_000
+    );
+    // A delta larger than the amount means the destination was credited some other way
+    running_token_balances.insert(2, 5_000);
+    running_token_balances.insert_post(2, 7_000);
+    assert_eq!(
+      running_token_balances.received_amount(&token_transfer),
+      1_000
+    );
+  }
+
+  #[test]
+  fn debit_floors_at_zero_and_marks_touched() {
+    let mut running_token_balances = RunningTokenBalances::new();
+    running_token_balances.insert(2, 100);
+    running_token_balances.debit(2, 300, 5);
+    // Untracked accounts are ignored
+    running_token_balances.credit(3, 300, 6);
+    assert_eq!(running_token_balances.get(2), Some(0));
+    assert_eq!(running_token_balances.get(3), None);
+    assert_eq!(
+      running_token_balances.touched().collect::<Vec<_>>(),
+      vec![(2, 5)]
+    );
+  }
+}
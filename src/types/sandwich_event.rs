@@ -0,0 +1,19 @@
+use crate::types::sandwich_victim::SandwichVictim;
+use solana_central::SwapTx;
+use solana_sdk::pubkey::Pubkey;
+
+/// A sandwich attack found by `detect_sandwiches`: a frontrun and backrun in opposite directions by
+/// the same attacker on the same market, surrounding one or more victim swaps in the frontrun
+/// direction.
+#[derive(Clone, Debug)]
+pub struct SandwichEvent {
+  pub market_address: Pubkey,
+  pub frontrun: SwapTx,
+  pub victims: Vec<SandwichVictim>,
+  pub backrun: SwapTx,
+  /// Token the attacker spent in the frontrun and got back in the backrun
+  pub profit_token: Pubkey,
+  /// Backrun output, scaled to the amount the frontrun bought, minus the frontrun input. In
+  /// `profit_token`, before transaction fees and tips
+  pub attacker_profit: i128,
+}
@@ -0,0 +1,10 @@
+use solana_central::SwapTx;
+
+/// A swap that was executed between the frontrun and backrun of a sandwich
+#[derive(Clone, Debug)]
+pub struct SandwichVictim {
+  pub swap: SwapTx,
+  /// How much less the victim received than without the frontrun, in the token the victim
+  /// received. `None` for pools that aren't constant product
+  pub loss: Option<u64>,
+}
@@ -0,0 +1,13 @@
+use crate::types::slot_rollback_reason::SlotRollbackReason;
+
+/// Notice that a slot will never be finalized, written by `GrpcDriver`. When the output of the slot
+/// was already released at processed or confirmed commitment, consumers have to undo it.
+#[derive(Clone, Debug)]
+pub struct SlotRollback {
+  pub slot: u64,
+  pub reason: SlotRollbackReason,
+  /// Number of decoded transactions the slot had
+  pub txs: usize,
+  /// Whether the decoded transactions were already written to the channels
+  pub released: bool,
+}
@@ -0,0 +1,8 @@
+/// Why a slot's buffered output was discarded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SlotRollbackReason {
+  /// The validator reported the slot as dead, with the reported error if there is one
+  Dead(Option<String>),
+  /// A later slot was finalized on a fork that doesn't include the slot
+  Skipped,
+}
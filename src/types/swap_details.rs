@@ -0,0 +1,35 @@
+use crate::types::swap_fees::SwapFees;
+use crate::types::swap_intent::SwapIntent;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+/// Decoded information about a swap that does not fit on the shared `SwapTx` type. Emitted once
+/// per `SwapTx` and linked to it by `signature` and `atomic_instruction_index`.
+#[derive(Clone, Debug)]
+pub struct SwapDetails {
+  pub signature: Signature,
+  pub atomic_instruction_index: u8,
+  /// Swap arguments from the swap instruction. `None` if the instruction could not be found
+  pub intent: Option<SwapIntent>,
+  /// `None` if the mint has no token balance in the transaction
+  pub token_a_decimals: Option<u8>,
+  pub token_b_decimals: Option<u8>,
+  /// `SwapTx::price_a_b_lp` adjusted for decimals, token A per whole token B. `None` if either
+  /// decimals are unknown
+  pub price_a_b_ui: Option<f64>,
+  /// `SwapTx::price_b_a_lp` adjusted for decimals, token B per whole token A
+  pub price_b_a_ui: Option<f64>,
+  /// Fee breakdown from the swap event. `None` for pools without a swap event (Raydium AMMv4 and
+  /// CPMM), whose fee is only known as `SwapTx::fee_fraction_lp`
+  pub fees: Option<SwapFees>,
+  /// Creator of the traded token, from Pumpfun and Pumpswap events. Creator self-trading shows up
+  /// as the creator being one of `SwapTx::signers`
+  pub creator: Option<Pubkey>,
+  /// Account the protocol fee was paid to, from Pumpfun and Pumpswap events
+  pub fee_recipient: Option<Pubkey>,
+  /// Whether the swap counted towards the user's Pumpfun trading volume rewards
+  pub track_volume: Option<bool>,
+  /// Bytes of the Pumpfun or Pumpswap swap event after the fields of its registered layout, such as
+  /// ix name strings or fields added by a program upgrade not yet in the registry
+  pub unknown_event_data: Vec<u8>,
+}
@@ -0,0 +1,12 @@
+/// Why a swap attempt failed, derived from the transaction error.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum SwapFailureKind {
+  /// The swap program rejected the outcome against the user's slippage limit
+  SlippageExceeded,
+  /// Not enough tokens or lamports, for the swap itself or for fees
+  InsufficientFunds,
+  /// A custom program error that isn't one of the above, see `custom_error_code`
+  CustomProgramError,
+  /// Any other transaction or instruction error
+  Other,
+}
@@ -0,0 +1,17 @@
+use solana_sdk::pubkey::Pubkey;
+
+/// Fees charged on a swap split by recipient, decoded from the protocol's swap event. All amounts
+/// are in `fee_token`.
+#[derive(Clone, Debug)]
+pub struct SwapFees {
+  /// Fee left in the pool for liquidity providers
+  pub lp_fee: u64,
+  pub protocol_fee: u64,
+  /// Fee paid to the token creator
+  pub creator_fee: u64,
+  /// Fee paid to the platform the token was launched on
+  pub platform_fee: u64,
+  /// Fee paid to the referrer of the swap
+  pub referral_fee: u64,
+  pub fee_token: Pubkey,
+}
@@ -0,0 +1,16 @@
+use crate::types::swap_mode::SwapMode;
+
+/// What the user asked for when submitting a swap, decoded from the swap instruction arguments
+/// rather than from the swap outcome. Used to study trader slippage settings.
+#[derive(Clone, Debug)]
+pub struct SwapIntent {
+  pub mode: SwapMode,
+  /// Amount fixed by the user. Input token amount for `ExactIn`, output token amount for `ExactOut`
+  pub specified_amount: u64,
+  /// Slippage limit. Minimum output token amount for `ExactIn`, maximum input token amount for
+  /// `ExactOut`
+  pub limit_amount: u64,
+  /// How far the limit was from the realized outcome in basis points of the realized amount. `None`
+  /// if the outcome is not known or the realized amount is zero
+  pub slippage_tolerance_bps: Option<u64>,
+}
@@ -0,0 +1,8 @@
+/// Which side of a swap the user fixed in the instruction arguments. For `ExactIn` the input amount
+/// is fixed and the output is bounded from below, for `ExactOut` the output amount is fixed and the
+/// input is bounded from above.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum SwapMode {
+  ExactIn,
+  ExactOut,
+}
@@ -0,0 +1,12 @@
+use solana_sdk::pubkey::Pubkey;
+
+/// A SOL transfer to a block engine tip account
+#[derive(Clone, Debug)]
+pub struct Tip {
+  pub payer: Pubkey,
+  /// Tip account the lamports were sent to, identifies the block engine
+  pub destination: Pubkey,
+  pub lamports: u64,
+  /// Top level instruction the transfer is in, or yielded by
+  pub instruction_index: u8,
+}
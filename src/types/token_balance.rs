@@ -0,0 +1,14 @@
+use solana_sdk::pubkey::Pubkey;
+
+/// Token account balance from transaction status meta, normalized across transaction sources.
+#[derive(Clone, Debug)]
+pub struct TokenBalance {
+  /// Index of the token account in the transaction account keys
+  pub account_index: u8,
+  pub mint: Pubkey,
+  /// Raw token amount, not adjusted for decimals
+  pub amount: u64,
+  pub decimals: u8,
+  /// Wallet that owns the token account. `None` for metas from before owners were recorded
+  pub owner: Option<Pubkey>,
+}
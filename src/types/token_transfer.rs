@@ -0,0 +1,22 @@
+/// A decoded SPL Token or Token-2022 transfer between two token accounts. Account fields are
+/// indexes into the transaction account keys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenTransfer {
+  pub source: u8,
+  pub destination: u8,
+  /// Amount debited from the source
+  pub amount: u64,
+  /// Transfer fee withheld at the destination, only known from `TransferCheckedWithFee`
+  pub fee: Option<u64>,
+  /// The transfer was made through Token-2022, whose mints can carry a transfer fee even when the
+  /// instruction doesn't state one
+  pub is_token_2022: bool,
+}
+
+impl TokenTransfer {
+  /// Amount credited to the destination when the fee is known from the instruction. A fee larger
+  /// than the amount is malformed data and credits nothing.
+  pub fn amount_after_fee(&self) -> u64 {
+    self.amount.saturating_sub(self.fee.unwrap_or(0))
+  }
+}
@@ -0,0 +1,30 @@
+use crate::types::tip::Tip;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+/// What it cost to land a transaction. Emitted once per analyzed transaction and linked by
+/// `signature` to the `SwapTx`s it contains.
+#[derive(Clone, Debug)]
+pub struct TxCost {
+  pub signature: Signature,
+  pub block_time: u64,
+  pub slot: u64,
+  pub index: u64,
+  /// First signer, who pays the fee
+  pub fee_payer: Pubkey,
+  /// Total fee charged in lamports, `base_fee` plus `priority_fee`
+  pub fee: u64,
+  /// Signature fee in lamports, 5000 per required signature
+  pub base_fee: u64,
+  /// Fee paid on top of the base fee in lamports
+  pub priority_fee: u64,
+  /// Requested compute unit limit, or the runtime default if none was set
+  pub compute_unit_limit: u32,
+  /// Compute unit price in micro-lamports per compute unit
+  pub compute_unit_price: u64,
+  /// `None` for metas from before compute units consumed was recorded
+  pub compute_units_consumed: Option<u64>,
+  /// Transfers to block engine tip accounts. Always empty for failed transactions, whose transfers
+  /// were rolled back
+  pub tips: Vec<Tip>,
+}
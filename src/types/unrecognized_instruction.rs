@@ -0,0 +1,20 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+/// An instruction or event from a supported program that could not be decoded, emitted instead of
+/// being dropped so protocol upgrades (new discriminators, event lengths or account layouts) show
+/// up as soon as they land.
+#[derive(Clone, Debug)]
+pub struct UnrecognizedInstruction {
+  pub signature: Signature,
+  pub block_time: u64,
+  pub slot: u64,
+  pub index: u64,
+  pub atomic_instruction_index: u8,
+  pub program_id: Pubkey,
+  /// Event discriminator for self CPI events, otherwise up to the first 8 bytes of the data
+  pub discriminator: Vec<u8>,
+  pub data_len: usize,
+  pub data: Vec<u8>,
+  pub num_accounts: usize,
+}
@@ -0,0 +1,20 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashMap;
+
+/// What a signer of a transaction gained or lost in it, computed from pre/post balances. Emitted
+/// once per signer.
+#[derive(Clone, Debug)]
+pub struct WalletDelta {
+  pub signature: Signature,
+  pub block_time: u64,
+  pub slot: u64,
+  pub index: u64,
+  pub wallet: Pubkey,
+  /// Net lamports, native plus wrapped SOL in token accounts owned by the wallet. Includes the
+  /// transaction fee for the fee payer
+  pub sol_change: i128,
+  /// Net raw token amount per mint over token accounts owned by the wallet, excluding wrapped SOL.
+  /// Mints without a net change are left out
+  pub token_changes: HashMap<Pubkey, i128>,
+}
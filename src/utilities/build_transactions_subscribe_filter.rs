@@ -0,0 +1,96 @@
+use crate::types::decode_options::DecodeOptions;
+use crate::types::decoder::Decoder;
+use yellowstone_grpc_proto::geyser::SubscribeRequestFilterTransactions;
+
+/// Build a Yellowstone transactions subscription that streams every transaction referencing a
+/// program of `decoders`. Vote transactions are always excluded and failed transactions unless
+/// `decode_failed_txs` is set in `options`. Programs excluded by the `filter` in `options` are
+/// excluded from the stream as well, the rest of the filter is left to `analyze_tx`. Returns `None`
+/// for an empty `decoders`, since a subscription without included accounts streams every non vote
+/// transaction.
+pub fn build_transactions_subscribe_filter(
+  decoders: &[Decoder],
+  options: &DecodeOptions,
+) -> Option<SubscribeRequestFilterTransactions> {
+  if decoders.is_empty() {
+    return None;
+  }
+  let mut account_include: Vec<String> = Vec::new();
+  for decoder in decoders {
+    for program_id in decoder.program_ids() {
+      let program_id = program_id.to_string();
+      if !account_include.contains(&program_id) {
+        account_include.push(program_id);
+      }
+    }
+  }
+  let account_exclude = options
+    .filter
+    .map(|filter| {
+      filter
+        .exclude_programs
+        .iter()
+        .map(|program_id| program_id.to_string())
+        .collect()
+    })
+    .unwrap_or_default();
+
+  Some(SubscribeRequestFilterTransactions {
+    vote: Some(false),
+    // `None` streams both failed and successful transactions
+    failed: if options.decode_failed_txs {
+      None
+    } else {
+      Some(false)
+    },
+    signature: None,
+    account_include,
+    account_exclude,
+    account_required: Vec::new(),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::types::decode_filter::DecodeFilter;
+  use solana_central::constants::RAYDIUM_CONSTANTS;
+  use solana_sdk::pubkey::Pubkey;
+
+  #[test]
+  fn empty_decoders_are_rejected() {
+    assert!(build_transactions_subscribe_filter(&[], &DecodeOptions::default()).is_none());
+  }
+
+  #[test]
+  fn includes_each_program_once() {
+    let filter = build_transactions_subscribe_filter(
+      &[Decoder::RaydiumCpmm, Decoder::RaydiumCpmm, Decoder::Routers],
+      &DecodeOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(filter.account_include.len(), 5);
+    assert_eq!(
+      filter.account_include[0],
+      RAYDIUM_CONSTANTS.cpmm_program.to_string()
+    );
+    assert_eq!(filter.vote, Some(false));
+    assert_eq!(filter.failed, Some(false));
+    assert!(filter.account_exclude.is_empty());
+  }
+
+  #[test]
+  fn follows_failed_txs_and_excluded_programs() {
+    let mut decode_filter = DecodeFilter::default();
+    let excluded_program = Pubkey::new_unique();
+    decode_filter.exclude_programs.insert(excluded_program);
+    let options = DecodeOptions {
+      decode_failed_txs: true,
+      filter: Some(&decode_filter),
+      ..Default::default()
+    };
+    let filter = build_transactions_subscribe_filter(&Decoder::ALL, &options).unwrap();
+    assert_eq!(filter.failed, None);
+    assert_eq!(filter.account_exclude, vec![excluded_program.to_string()]);
+  }
+}
@@ -0,0 +1,84 @@
+use crate::types::swap_intent::SwapIntent;
+use crate::types::swap_mode::SwapMode;
+
+/// Compute the effective slippage tolerance of a swap in basis points. For `ExactIn` swaps this is
+/// how far below the received amount the minimum output was set, for `ExactOut` swaps how far
+/// above the spent amount the maximum input was set. Returns `None` if the realized amount it is
+/// measured against is zero.
+pub fn compute_slippage_tolerance_bps(
+  intent: &SwapIntent,
+  swapped_amount_in: u64,
+  swapped_amount_received: u64,
+) -> Option<u64> {
+  let (realized, slack) = match intent.mode {
+    SwapMode::ExactIn => (
+      swapped_amount_received,
+      swapped_amount_received.saturating_sub(intent.limit_amount),
+    ),
+    SwapMode::ExactOut => (
+      swapped_amount_in,
+      intent.limit_amount.saturating_sub(swapped_amount_in),
+    ),
+  };
+  if realized == 0 {
+    return None;
+  }
+  // Limits like u64::MAX for "no limit" can overflow u64 bps, cap instead of wrapping
+  Some((slack as u128 * 10000 / realized as u128).min(u64::MAX as u128) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn intent(mode: SwapMode, limit_amount: u64) -> SwapIntent {
+    SwapIntent {
+      mode,
+      specified_amount: 0,
+      limit_amount,
+      slippage_tolerance_bps: None,
+    }
+  }
+
+  #[test]
+  fn exact_in_measures_slack_below_received_amount() {
+    let intent = intent(SwapMode::ExactIn, 9_900);
+    assert_eq!(
+      compute_slippage_tolerance_bps(&intent, 1_000, 10_000),
+      Some(100)
+    );
+  }
+
+  #[test]
+  fn exact_out_measures_slack_above_spent_amount() {
+    let intent = intent(SwapMode::ExactOut, 10_500);
+    assert_eq!(
+      compute_slippage_tolerance_bps(&intent, 10_000, 1_000),
+      Some(500)
+    );
+  }
+
+  #[test]
+  fn limit_past_the_outcome_is_zero() {
+    let intent = intent(SwapMode::ExactIn, 20_000);
+    assert_eq!(
+      compute_slippage_tolerance_bps(&intent, 1_000, 10_000),
+      Some(0)
+    );
+  }
+
+  #[test]
+  fn zero_realized_amount_is_none() {
+    let intent = intent(SwapMode::ExactIn, 0);
+    assert_eq!(compute_slippage_tolerance_bps(&intent, 1_000, 0), None);
+  }
+
+  #[test]
+  fn no_limit_does_not_overflow() {
+    let intent = intent(SwapMode::ExactOut, u64::MAX);
+    assert_eq!(
+      compute_slippage_tolerance_bps(&intent, 1, 1),
+      Some(u64::MAX)
+    );
+  }
+}
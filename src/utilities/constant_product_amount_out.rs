@@ -0,0 +1,20 @@
+use solana_central::constants::LAMPORTS_PER_SOL;
+
+/// Amount a constant product pool pays out for `amount_in` given the reserves before the swap.
+/// `fee_fraction` is the fee taken from the input, scaled by `LAMPORTS_PER_SOL` like
+/// `SwapTx::fee_fraction_lp`.
+pub fn constant_product_amount_out(
+  amount_in: u64,
+  reserve_in: u64,
+  reserve_out: u64,
+  fee_fraction: u64,
+) -> u64 {
+  let fee_fraction = (fee_fraction as u128).min(LAMPORTS_PER_SOL);
+  let amount_in_after_fee =
+    amount_in as u128 * (LAMPORTS_PER_SOL - fee_fraction) / LAMPORTS_PER_SOL;
+  let denominator = reserve_in as u128 + amount_in_after_fee;
+  if denominator == 0 {
+    return 0;
+  }
+  (amount_in_after_fee * reserve_out as u128 / denominator) as u64
+}
@@ -0,0 +1,9 @@
+use borsh::BorshDeserialize;
+
+/// Decode the known leading fields of an event and return them with the unknown tail bytes after
+/// them. Fields appended by program upgrades end up in the tail instead of failing the decode.
+pub fn decode_event_prefix<T: BorshDeserialize>(data: &[u8]) -> Option<(T, &[u8])> {
+  let mut tail = data;
+  let decoded = T::deserialize(&mut tail).ok()?;
+  Some((decoded, tail))
+}
@@ -1 +1,8 @@
-pub mod fetch_token_metadata_from_uri;
\ No newline at end of file
+pub mod compute_slippage_tolerance_bps;
+pub mod fetch_token_metadata_from_uri;
+pub mod constant_product_amount_out;
+pub mod build_transactions_subscribe_filter;
+pub mod decode_event_prefix;
+pub mod swap_tokens;
+#[cfg(test)]
+pub mod test_swap_tx_builder;
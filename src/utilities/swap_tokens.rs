@@ -0,0 +1,11 @@
+use solana_central::SwapDirection;
+use solana_central::SwapTx;
+use solana_sdk::pubkey::Pubkey;
+
+/// Token spent and token received by a swap
+pub fn swap_tokens(swap: &SwapTx) -> (Pubkey, Pubkey) {
+  match swap.direction {
+    SwapDirection::AToB => (swap.token_a_address, swap.token_b_address),
+    SwapDirection::BToA => (swap.token_b_address, swap.token_a_address),
+  }
+}
@@ -0,0 +1,91 @@
+use solana_central::Pools;
+use solana_central::SwapDirection;
+use solana_central::SwapTx;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashSet;
+
+/// Builder of `SwapTx`s for tests of the analyses over decoded swaps. By default the swap is a
+/// Raydium CPMM A to B swap of zero amounts on a new market between new mints, signed by a new
+/// wallet at the start of slot 1, with fixed vault amounts and zero prices.
+pub struct TestSwapTxBuilder {
+  swap_tx: SwapTx,
+}
+
+impl Default for TestSwapTxBuilder {
+  fn default() -> Self {
+    TestSwapTxBuilder {
+      swap_tx: SwapTx {
+        pool: Pools::RaydiumCpmm,
+        direction: SwapDirection::AToB,
+        block_time: 0,
+        slot: 1,
+        index: 0,
+        atomic_instruction_index: 0,
+        fee_fraction_lp: 0,
+        swapped_amount_in: 0,
+        swapped_amount_received: 0,
+        pool_token_a_vault_amount: 1_000_000,
+        pool_token_b_vault_amount: 1_000_000,
+        price_a_b_lp: 0,
+        price_b_a_lp: 0,
+        token_a_address: Pubkey::new_unique(),
+        token_b_address: Pubkey::new_unique(),
+        market_address: Pubkey::new_unique(),
+        signature: Signature::new_unique(),
+        signers: HashSet::from([Pubkey::new_unique()]),
+      },
+    }
+  }
+}
+
+impl TestSwapTxBuilder {
+  pub fn pool(mut self, pool: Pools) -> Self {
+    self.swap_tx.pool = pool;
+    self
+  }
+
+  pub fn market(mut self, market_address: Pubkey) -> Self {
+    self.swap_tx.market_address = market_address;
+    self
+  }
+
+  pub fn tokens(mut self, token_a_address: Pubkey, token_b_address: Pubkey) -> Self {
+    self.swap_tx.token_a_address = token_a_address;
+    self.swap_tx.token_b_address = token_b_address;
+    self
+  }
+
+  pub fn direction(mut self, direction: SwapDirection) -> Self {
+    self.swap_tx.direction = direction;
+    self
+  }
+
+  /// Amount spent of the input token and received of the output token
+  pub fn amounts(mut self, swapped_amount_in: u64, swapped_amount_received: u64) -> Self {
+    self.swap_tx.swapped_amount_in = swapped_amount_in;
+    self.swap_tx.swapped_amount_received = swapped_amount_received;
+    self
+  }
+
+  pub fn prices(mut self, price_a_b_lp: u128, price_b_a_lp: u128) -> Self {
+    self.swap_tx.price_a_b_lp = price_a_b_lp;
+    self.swap_tx.price_b_a_lp = price_b_a_lp;
+    self
+  }
+
+  pub fn signer(mut self, signer: Pubkey) -> Self {
+    self.swap_tx.signers = HashSet::from([signer]);
+    self
+  }
+
+  /// Position of the transaction in its block
+  pub fn index(mut self, index: u64) -> Self {
+    self.swap_tx.index = index;
+    self
+  }
+
+  pub fn build(self) -> SwapTx {
+    self.swap_tx
+  }
+}